@@ -0,0 +1,591 @@
+//! Actisense NGT-1 binary protocol and N2K ASCII format.
+//!
+//! The NGT-1 gateway talks to its host with binary messages framed by `DLE STX` and `DLE ETX`,
+//! with any `DLE` in the message doubled. Each message holds a command byte, a length byte, the
+//! data and a checksum which makes the sum of all of them zero modulo 256. Actisense's newer
+//! gateways and tools also use a line based ASCII format, one NMEA 2000 message per line:
+//!
+//! ```text
+//! A173321.107 23FF7 1F513 012F3070002F30709F
+//! ```
+//!
+//! That is the time of day, then the source address, destination address and priority, then the
+//! PGN and the data, all in hex.
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use can::{CanId, RawMessage};
+use hex;
+
+const DLE: u8 = 0x10;
+const STX: u8 = 0x02;
+const ETX: u8 = 0x03;
+
+/// Command of an NMEA 2000 message received by the NGT-1.
+pub const N2K_MSG_RECEIVED: u8 = 0x93;
+/// Command of an NMEA 2000 message for the NGT-1 to send.
+pub const N2K_MSG_SEND: u8 = 0x94;
+/// Command of a BEM response from the NGT-1.
+pub const BEM_RESPONSE: u8 = 0xa0;
+/// Command of a BEM command to the NGT-1.
+pub const BEM_COMMAND: u8 = 0xa1;
+
+/// Errors which may occur while parsing Actisense data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActisenseError {
+    /// The checksum of an NGT-1 message does not match its contents.
+    Checksum,
+    /// A length in the message does not match the amount of data in it.
+    Length,
+    /// The message is not laid out as the format requires.
+    Syntax,
+}
+
+impl fmt::Display for ActisenseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ActisenseError::Checksum => write!(f, "checksum mismatch"),
+            ActisenseError::Length => write!(f, "length does not match data"),
+            ActisenseError::Syntax => write!(f, "malformed message"),
+        }
+    }
+}
+
+impl Error for ActisenseError {}
+
+/// A message to or from an NGT-1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ngt1Message {
+    /// An NMEA 2000 message received from the bus, with the gateway's timestamp in milliseconds.
+    Received {
+        /// Time the gateway received the message, from an arbitrary starting point.
+        timestamp: Duration,
+        /// The received message.
+        message: RawMessage,
+    },
+    /// An NMEA 2000 message for the gateway to send. The gateway sends it with its own source
+    /// address, so the source address of the identifier is not transmitted.
+    Send(RawMessage),
+    /// A response from the gateway to a BEM command.
+    BemResponse {
+        /// Which command the response is to.
+        id: u8,
+        /// Contents of the response.
+        data: Vec<u8>,
+    },
+    /// A command which configures or queries the gateway.
+    BemCommand {
+        /// Which command to carry out.
+        id: u8,
+        /// Arguments of the command.
+        data: Vec<u8>,
+    },
+    /// Any other message.
+    Other {
+        /// Command byte of the message.
+        command: u8,
+        /// Contents of the message.
+        data: Vec<u8>,
+    },
+}
+
+impl Ngt1Message {
+    /// The BEM command which tells the gateway to pass every PGN on to the host, which is not
+    /// what it does out of the box.
+    pub fn receive_all() -> Ngt1Message {
+        Ngt1Message::BemCommand {
+            id: 0x11,
+            data: vec![0x02, 0x00],
+        }
+    }
+
+    /// Parses the command byte and data of a message which has been unframed and checked.
+    fn parse(command: u8, data: &[u8]) -> Result<Ngt1Message, ActisenseError> {
+        let message = match command {
+            N2K_MSG_RECEIVED => {
+                if data.len() < 11 || data.len() != 11 + data[10] as usize {
+                    return Err(ActisenseError::Length);
+                }
+                let pgn = u32::from(data[1]) | u32::from(data[2]) << 8 | u32::from(data[3]) << 16;
+                let millis = u32::from(data[6])
+                    | u32::from(data[7]) << 8
+                    | u32::from(data[8]) << 16
+                    | u32::from(data[9]) << 24;
+                Ngt1Message::Received {
+                    timestamp: Duration::from_millis(u64::from(millis)),
+                    message: RawMessage {
+                        id: CanId::new(data[0], pgn, data[5], data[4]),
+                        data: data[11..].to_vec(),
+                    },
+                }
+            }
+            N2K_MSG_SEND => {
+                if data.len() < 6 || data.len() != 6 + data[5] as usize {
+                    return Err(ActisenseError::Length);
+                }
+                let pgn = u32::from(data[1]) | u32::from(data[2]) << 8 | u32::from(data[3]) << 16;
+                Ngt1Message::Send(RawMessage {
+                    id: CanId::new(data[0], pgn, 0, data[4]),
+                    data: data[6..].to_vec(),
+                })
+            }
+            BEM_RESPONSE | BEM_COMMAND => {
+                let (&id, rest) = data.split_first().ok_or(ActisenseError::Length)?;
+                if command == BEM_RESPONSE {
+                    Ngt1Message::BemResponse {
+                        id,
+                        data: rest.to_vec(),
+                    }
+                } else {
+                    Ngt1Message::BemCommand {
+                        id,
+                        data: rest.to_vec(),
+                    }
+                }
+            }
+            _ => Ngt1Message::Other {
+                command,
+                data: data.to_vec(),
+            },
+        };
+
+        Ok(message)
+    }
+
+    /// The command byte and data of the message.
+    fn contents(&self) -> (u8, Vec<u8>) {
+        match *self {
+            Ngt1Message::Received {
+                timestamp,
+                ref message,
+            } => {
+                let pgn = message.id.pgn();
+                let millis = timestamp.as_millis() as u32;
+                let mut data = vec![
+                    message.id.priority(),
+                    pgn as u8,
+                    (pgn >> 8) as u8,
+                    (pgn >> 16) as u8,
+                    message.id.destination(),
+                    message.id.source(),
+                    millis as u8,
+                    (millis >> 8) as u8,
+                    (millis >> 16) as u8,
+                    (millis >> 24) as u8,
+                    message.data.len() as u8,
+                ];
+                data.extend_from_slice(&message.data);
+                (N2K_MSG_RECEIVED, data)
+            }
+            Ngt1Message::Send(ref message) => {
+                let pgn = message.id.pgn();
+                let mut data = vec![
+                    message.id.priority(),
+                    pgn as u8,
+                    (pgn >> 8) as u8,
+                    (pgn >> 16) as u8,
+                    message.id.destination(),
+                    message.data.len() as u8,
+                ];
+                data.extend_from_slice(&message.data);
+                (N2K_MSG_SEND, data)
+            }
+            Ngt1Message::BemResponse { id, ref data } => (
+                BEM_RESPONSE,
+                Some(id).into_iter().chain(data.iter().cloned()).collect(),
+            ),
+            Ngt1Message::BemCommand { id, ref data } => (
+                BEM_COMMAND,
+                Some(id).into_iter().chain(data.iter().cloned()).collect(),
+            ),
+            Ngt1Message::Other { command, ref data } => (command, data.clone()),
+        }
+    }
+
+    /// Encodes the message with its framing, ready to be written to the gateway.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::actisense::Ngt1Message;
+    ///
+    /// let bytes = Ngt1Message::receive_all().to_bytes();
+    ///
+    /// assert_eq!(bytes, vec![0x10, 0x02, 0xa1, 0x03, 0x11, 0x02, 0x00, 0x49, 0x10, 0x03]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (command, data) = self.contents();
+
+        let mut body = vec![command, data.len() as u8];
+        body.extend_from_slice(&data);
+        let sum = body.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        body.push(0u8.wrapping_sub(sum));
+
+        let mut bytes = vec![DLE, STX];
+        for byte in body {
+            if byte == DLE {
+                bytes.push(DLE);
+            }
+            bytes.push(byte);
+        }
+        bytes.extend_from_slice(&[DLE, ETX]);
+        bytes
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Idle,
+    Start,
+    Message,
+    Escape,
+}
+
+/// Pulls NGT-1 messages out of a stream of bytes from the gateway.
+///
+/// Bytes outside of a message are skipped, so the decoder may be started part way through the
+/// stream.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::actisense::{Ngt1Decoder, Ngt1Message};
+///
+/// let mut decoder = Ngt1Decoder::new();
+/// let bytes = [0x10, 0x02, 0xa0, 0x02, 0x11, 0x01, 0x4c, 0x10, 0x03];
+///
+/// let messages = decoder.feed(&bytes);
+/// assert_eq!(messages, vec![Ok(Ngt1Message::BemResponse { id: 0x11, data: vec![0x01] })]);
+/// ```
+#[derive(Debug)]
+pub struct Ngt1Decoder {
+    state: State,
+    buffer: Vec<u8>,
+}
+
+impl Ngt1Decoder {
+    /// Creates a decoder waiting for the start of a message.
+    pub fn new() -> Ngt1Decoder {
+        Ngt1Decoder {
+            state: State::Idle,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Adds a byte from the stream, returning the message it completed, if any.
+    pub fn push(&mut self, byte: u8) -> Option<Result<Ngt1Message, ActisenseError>> {
+        match (self.state, byte) {
+            (State::Idle, DLE) => self.state = State::Start,
+            (State::Idle, _) => {}
+            (State::Start, STX) => {
+                self.buffer.clear();
+                self.state = State::Message;
+            }
+            (State::Start, DLE) => {}
+            (State::Start, _) => self.state = State::Idle,
+            (State::Message, DLE) => self.state = State::Escape,
+            (State::Message, _) => self.buffer.push(byte),
+            (State::Escape, DLE) => {
+                self.buffer.push(DLE);
+                self.state = State::Message;
+            }
+            (State::Escape, STX) => {
+                // The end of the previous message was lost.
+                self.buffer.clear();
+                self.state = State::Message;
+            }
+            (State::Escape, ETX) => {
+                self.state = State::Idle;
+                return Some(self.finish());
+            }
+            (State::Escape, _) => {
+                self.state = State::Idle;
+                return Some(Err(ActisenseError::Syntax));
+            }
+        }
+
+        None
+    }
+
+    /// Adds bytes from the stream, returning every message they completed.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<Result<Ngt1Message, ActisenseError>> {
+        bytes.iter().filter_map(|&b| self.push(b)).collect()
+    }
+
+    fn finish(&mut self) -> Result<Ngt1Message, ActisenseError> {
+        let body = &self.buffer;
+        if body.len() < 3 || body[1] as usize != body.len() - 3 {
+            return Err(ActisenseError::Length);
+        }
+        if body.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) != 0 {
+            return Err(ActisenseError::Checksum);
+        }
+
+        Ngt1Message::parse(body[0], &body[2..body.len() - 1])
+    }
+}
+
+impl Default for Ngt1Decoder {
+    fn default() -> Ngt1Decoder {
+        Ngt1Decoder::new()
+    }
+}
+
+/// A message in the Actisense N2K ASCII format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsciiMessage {
+    /// Time of day the message was received, since midnight.
+    pub timestamp: Duration,
+    /// The message.
+    pub message: RawMessage,
+}
+
+impl AsciiMessage {
+    /// Parses a line of N2K ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::actisense::AsciiMessage;
+    ///
+    /// let line = AsciiMessage::parse("A173321.107 23FF7 1F513 012F3070002F30709F").unwrap();
+    ///
+    /// assert_eq!(line.message.id.source(), 0x23);
+    /// assert_eq!(line.message.id.pgn(), 128275);
+    /// assert_eq!(line.to_string(), "A173321.107 23FF7 1F513 012F3070002F30709F");
+    /// assert!(AsciiMessage::parse("A173321.éé 23FF7 1F513 00").is_err());
+    /// ```
+    pub fn parse(line: &str) -> Result<AsciiMessage, ActisenseError> {
+        let line = line.trim();
+        if !line.starts_with('A') {
+            return Err(ActisenseError::Syntax);
+        }
+
+        let mut parts = line[1..].split_whitespace();
+        let time = parts.next().ok_or(ActisenseError::Syntax)?;
+        let header = parts.next().ok_or(ActisenseError::Syntax)?;
+        let pgn = parts.next().ok_or(ActisenseError::Syntax)?;
+        let data = parts.next().unwrap_or("");
+        if parts.next().is_some() || header.len() != 5 {
+            return Err(ActisenseError::Syntax);
+        }
+
+        let timestamp = parse_time(time).ok_or(ActisenseError::Syntax)?;
+        let header = hex::decode(&format!("{}0", header)).ok_or(ActisenseError::Syntax)?;
+        let pgn = u32::from_str_radix(pgn, 16).map_err(|_| ActisenseError::Syntax)?;
+        let data = hex::decode(data).ok_or(ActisenseError::Syntax)?;
+
+        Ok(AsciiMessage {
+            timestamp,
+            message: RawMessage {
+                id: CanId::new(header[2] >> 4, pgn, header[0], header[1]),
+                data,
+            },
+        })
+    }
+}
+
+impl fmt::Display for AsciiMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let seconds = self.timestamp.as_secs();
+        let id = self.message.id;
+        write!(
+            f,
+            "A{:02}{:02}{:02}.{:03} {:02X}{:02X}{:X} {:X} {}",
+            seconds / 3600 % 24,
+            seconds / 60 % 60,
+            seconds % 60,
+            self.timestamp.subsec_millis(),
+            id.source(),
+            id.destination(),
+            id.priority(),
+            id.pgn(),
+            hex::encode(&self.message.data, "")
+        )
+    }
+}
+
+/// Parses a time of day written as `hhmmss.ddd`.
+fn parse_time(text: &str) -> Option<Duration> {
+    let (whole, fraction) = match text.find('.') {
+        Some(dot) => (&text[..dot], &text[dot + 1..]),
+        None => (text, ""),
+    };
+    let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if whole.len() != 6 || !digits(whole) || !digits(fraction) {
+        return None;
+    }
+
+    let hours: u64 = whole[0..2].parse().ok()?;
+    let minutes: u64 = whole[2..4].parse().ok()?;
+    let seconds: u64 = whole[4..6].parse().ok()?;
+    if hours > 23 || minutes > 59 || seconds > 59 {
+        return None;
+    }
+    let millis = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<3}", fraction)[..3].parse().ok()?
+    };
+
+    Some(Duration::from_millis(
+        ((hours * 60 + minutes) * 60 + seconds) * 1000 + millis,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn received() -> Ngt1Message {
+        Ngt1Message::Received {
+            timestamp: Duration::from_millis(0x1010),
+            message: RawMessage {
+                id: CanId::new(2, 127250, 0x10, 0xff),
+                data: vec![0x00, 0x10, 0x10, 0xff, 0x7f, 0xff, 0x7f, 0xfd],
+            },
+        }
+    }
+
+    #[test]
+    fn round_trip_with_escapes() {
+        let bytes = received().to_bytes();
+
+        assert_eq!(Ngt1Decoder::new().feed(&bytes), vec![Ok(received())]);
+    }
+
+    #[test]
+    fn send_round_trip() {
+        let message = Ngt1Message::Send(RawMessage {
+            id: CanId::new(3, 59904, 0, 0x23),
+            data: vec![0x00, 0xee, 0x00],
+        });
+        let bytes = message.to_bytes();
+
+        assert_eq!(Ngt1Decoder::new().feed(&bytes), vec![Ok(message)]);
+    }
+
+    #[test]
+    fn bytes_outside_messages_are_skipped() {
+        let mut bytes = vec![0x00, 0x10, 0x10, 0x03, 0x10, 0x05];
+        bytes.extend(received().to_bytes());
+
+        assert_eq!(Ngt1Decoder::new().feed(&bytes), vec![Ok(received())]);
+    }
+
+    #[test]
+    fn lost_end_of_message() {
+        let mut bytes = vec![0x10, 0x02, 0xa0, 0x02];
+        bytes.extend(received().to_bytes());
+
+        assert_eq!(Ngt1Decoder::new().feed(&bytes), vec![Ok(received())]);
+    }
+
+    #[test]
+    fn bad_checksum() {
+        let bytes = [0x10, 0x02, 0xa0, 0x02, 0x11, 0x01, 0x4d, 0x10, 0x03];
+
+        assert_eq!(
+            Ngt1Decoder::new().feed(&bytes),
+            vec![Err(ActisenseError::Checksum)]
+        );
+    }
+
+    #[test]
+    fn bad_lengths() {
+        // The length byte counts 3 bytes of data where there are 2.
+        let bytes = [0x10, 0x02, 0xa0, 0x03, 0x11, 0x01, 0x4b, 0x10, 0x03];
+        assert_eq!(
+            Ngt1Decoder::new().feed(&bytes),
+            vec![Err(ActisenseError::Length)]
+        );
+
+        let bytes = [0x10, 0x02, 0xa0, 0x10, 0x03];
+        assert_eq!(
+            Ngt1Decoder::new().feed(&bytes),
+            vec![Err(ActisenseError::Length)]
+        );
+
+        // A received message whose payload is one byte shorter than it claims.
+        let mut message = Ngt1Message::Other {
+            command: N2K_MSG_RECEIVED,
+            data: vec![2, 0x12, 0xf1, 0x01, 0xff, 0x01, 0, 0, 0, 0, 2, 0x00],
+        };
+        assert_eq!(
+            Ngt1Decoder::new().feed(&message.to_bytes()),
+            vec![Err(ActisenseError::Length)]
+        );
+
+        message = Ngt1Message::Other {
+            command: BEM_COMMAND,
+            data: vec![],
+        };
+        assert_eq!(
+            Ngt1Decoder::new().feed(&message.to_bytes()),
+            vec![Err(ActisenseError::Length)]
+        );
+    }
+
+    #[test]
+    fn unknown_escape() {
+        let bytes = [0x10, 0x02, 0xa0, 0x10, 0x04, 0x10, 0x03];
+
+        assert_eq!(
+            Ngt1Decoder::new().feed(&bytes),
+            vec![Err(ActisenseError::Syntax)]
+        );
+    }
+
+    #[test]
+    fn ascii_round_trip() {
+        let line = "A000102.030 01FF2 1F801 FF";
+        let message = AsciiMessage::parse(line).unwrap();
+
+        assert_eq!(message.timestamp, Duration::from_millis(62_030));
+        assert_eq!(message.message.id.destination(), 0xff);
+        assert_eq!(message.message.id.priority(), 2);
+        assert_eq!(message.to_string(), line);
+    }
+
+    #[test]
+    fn ascii_without_data_or_fraction() {
+        let message = AsciiMessage::parse("A235959 01FF2 1F801").unwrap();
+
+        assert_eq!(message.timestamp, Duration::from_secs(86_399));
+        assert!(message.message.data.is_empty());
+    }
+
+    #[test]
+    fn rejected_ascii_lines() {
+        for line in &[
+            "",
+            "173321.107 23FF7 1F513 00",
+            "A173321.107",
+            "A173321.107 23FF7",
+            "A173321.107 23FF7 1F513 00 00",
+            "A173321.107 23FF 1F513 00",
+            "A173321.107 23FF77 1F513 00",
+            "A173321.107 23FG7 1F513 00",
+            "A173321.107 23FF7 1F5G3 00",
+            "A173321.107 23FF7 1F513 0",
+            "A173321.107 23FF7 1F513 0G",
+            "A17332.107 23FF7 1F513 00",
+            "A1733211.107 23FF7 1F513 00",
+            "A17332a.107 23FF7 1F513 00",
+            "A173321.1a7 23FF7 1F513 00",
+            "A173321.+07 23FF7 1F513 00",
+            "A243321.107 23FF7 1F513 00",
+            "A176021.107 23FF7 1F513 00",
+            "A173360.107 23FF7 1F513 00",
+        ] {
+            assert_eq!(
+                AsciiMessage::parse(line),
+                Err(ActisenseError::Syntax),
+                "{}",
+                line
+            );
+        }
+    }
+}
@@ -0,0 +1,1339 @@
+//! Automatic Identification System messages.
+//!
+//! AIS transceivers pass the messages they receive to other equipment either as NMEA 0183
+//! `!AIVDM` sentences, which are split into fragments by
+//! [Vdm](../nmea0183/struct.Vdm.html), or as NMEA 2000 PGNs. Either way they decode into the
+//! structs here.
+//!
+//! In NMEA 0183 each message is a string of bits, sent six at a time as printable characters:
+//!
+//! ```text
+//! !AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C
+//! ```
+//!
+//! The payload is the fifth field, and the sixth is the number of fill bits added to the end of
+//! it to make a whole number of characters.
+//!
+//! Latitudes are in degrees north and longitudes in degrees east, speeds in knots, and courses
+//! and headings in degrees from true north. Values the sender marks as not available are `None`.
+//! Values from NMEA 2000 are rounded to the resolution AIS sends them with, so a target looks the
+//! same whichever bus it was received from.
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use calendar;
+use decode::{self, Value};
+use nmea0183::Date;
+
+/// Errors which may occur while decoding an AIS message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AisError {
+    /// The payload contains a character which is not part of the six bit armoring.
+    Armoring(char),
+    /// The number of fill bits is more than 5 or more than the payload has.
+    FillBits(u8),
+    /// The message is shorter than its type requires.
+    Length,
+    /// The message type is not one this crate decodes.
+    UnsupportedMessage(u8),
+}
+
+impl fmt::Display for AisError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AisError::Armoring(c) => write!(f, "invalid payload character {:?}", c),
+            AisError::FillBits(bits) => write!(f, "invalid number of fill bits {}", bits),
+            AisError::Length => write!(f, "message too short"),
+            AisError::UnsupportedMessage(kind) => write!(f, "unsupported message type {}", kind),
+        }
+    }
+}
+
+impl Error for AisError {}
+
+/// Dimensions of a vessel or aid to navigation, in metres from the reference point for its
+/// reported position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dimensions {
+    /// Distance to the bow.
+    pub to_bow: Option<u16>,
+    /// Distance to the stern.
+    pub to_stern: Option<u16>,
+    /// Distance to the port side.
+    pub to_port: Option<u16>,
+    /// Distance to the starboard side.
+    pub to_starboard: Option<u16>,
+}
+
+/// Estimated time of arrival, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Eta {
+    /// Month, from 1 to 12.
+    pub month: Option<u8>,
+    /// Day of the month, from 1.
+    pub day: Option<u8>,
+    /// Hour, from 0 to 23.
+    pub hour: Option<u8>,
+    /// Minute, from 0 to 59.
+    pub minute: Option<u8>,
+}
+
+/// Class A position report, message types 1, 2 and 3.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionReport {
+    /// Message type: 1 for scheduled, 2 for assigned scheduled and 3 for interrogated reports.
+    pub message_type: u8,
+    /// Number of times the message has been repeated.
+    pub repeat: u8,
+    /// MMSI of the vessel.
+    pub mmsi: u32,
+    /// Navigational status, named by [NAVIGATION_STATUS](../lookup/static.NAVIGATION_STATUS.html).
+    pub navigation_status: u8,
+    /// Rate of turn, in degrees per minute. Positive turns are to starboard.
+    pub rate_of_turn: Option<f64>,
+    /// Speed over ground.
+    pub speed: Option<f64>,
+    /// Whether the position is accurate to better than 10 metres.
+    pub high_accuracy: bool,
+    /// Longitude, in degrees.
+    pub longitude: Option<f64>,
+    /// Latitude, in degrees.
+    pub latitude: Option<f64>,
+    /// Course over ground.
+    pub course: Option<f64>,
+    /// True heading.
+    pub heading: Option<u16>,
+    /// Second of the UTC minute the report was generated.
+    pub second: Option<u8>,
+    /// Special maneuver indicator: 1 when not engaged in one and 2 when engaged in one.
+    pub maneuver: u8,
+    /// Whether Receiver Autonomous Integrity Monitoring is in use.
+    pub raim: bool,
+    /// Communication state of the radio.
+    pub radio: u32,
+}
+
+/// Base station report, message type 4.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseStationReport {
+    /// Number of times the message has been repeated.
+    pub repeat: u8,
+    /// MMSI of the base station.
+    pub mmsi: u32,
+    /// UTC date.
+    pub date: Option<Date>,
+    /// UTC time of day, since midnight.
+    pub time: Option<Duration>,
+    /// Whether the position is accurate to better than 10 metres.
+    pub high_accuracy: bool,
+    /// Longitude, in degrees.
+    pub longitude: Option<f64>,
+    /// Latitude, in degrees.
+    pub latitude: Option<f64>,
+    /// Type of position fixing device, named by
+    /// [POSITION_FIX_DEVICE](../lookup/static.POSITION_FIX_DEVICE.html).
+    pub epfd: u8,
+    /// Whether Receiver Autonomous Integrity Monitoring is in use.
+    pub raim: bool,
+    /// Communication state of the radio.
+    pub radio: u32,
+}
+
+/// Class A static and voyage related data, message type 5.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticAndVoyageData {
+    /// Number of times the message has been repeated.
+    pub repeat: u8,
+    /// MMSI of the vessel.
+    pub mmsi: u32,
+    /// Version of ITU-R M.1371 the transceiver complies with, 0 being the first.
+    pub ais_version: u8,
+    /// IMO ship identification number.
+    pub imo: Option<u32>,
+    /// Radio call sign.
+    pub callsign: String,
+    /// Name of the vessel.
+    pub name: String,
+    /// Type of ship and cargo, named by [SHIP_TYPE](../lookup/static.SHIP_TYPE.html).
+    pub ship_type: u8,
+    /// Dimensions of the vessel.
+    pub dimensions: Dimensions,
+    /// Type of position fixing device, named by
+    /// [POSITION_FIX_DEVICE](../lookup/static.POSITION_FIX_DEVICE.html).
+    pub epfd: u8,
+    /// Estimated time of arrival at the destination.
+    pub eta: Eta,
+    /// Maximum present static draught, in metres.
+    pub draught: Option<f64>,
+    /// Destination.
+    pub destination: String,
+    /// Whether the data terminal equipment is ready.
+    pub dte_ready: bool,
+}
+
+/// Standard class B position report, message type 18.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassBPositionReport {
+    /// Number of times the message has been repeated.
+    pub repeat: u8,
+    /// MMSI of the vessel.
+    pub mmsi: u32,
+    /// Speed over ground.
+    pub speed: Option<f64>,
+    /// Whether the position is accurate to better than 10 metres.
+    pub high_accuracy: bool,
+    /// Longitude, in degrees.
+    pub longitude: Option<f64>,
+    /// Latitude, in degrees.
+    pub latitude: Option<f64>,
+    /// Course over ground.
+    pub course: Option<f64>,
+    /// True heading.
+    pub heading: Option<u16>,
+    /// Second of the UTC minute the report was generated.
+    pub second: Option<u8>,
+    /// Whether the unit is a carrier sense unit rather than a SOTDMA unit.
+    pub carrier_sense: bool,
+    /// Whether the unit has a display for messages 12 and 14.
+    pub display: bool,
+    /// Whether the unit has digital selective calling.
+    pub dsc: bool,
+    /// Whether the unit can use the whole marine band.
+    pub band: bool,
+    /// Whether the unit accepts channel management by message 22.
+    pub message_22: bool,
+    /// Whether the unit is in assigned mode rather than autonomous mode.
+    pub assigned: bool,
+    /// Whether Receiver Autonomous Integrity Monitoring is in use.
+    pub raim: bool,
+    /// Communication state of the radio.
+    pub radio: u32,
+}
+
+/// Extended class B position report, message type 19.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtendedClassBPositionReport {
+    /// Number of times the message has been repeated.
+    pub repeat: u8,
+    /// MMSI of the vessel.
+    pub mmsi: u32,
+    /// Speed over ground.
+    pub speed: Option<f64>,
+    /// Whether the position is accurate to better than 10 metres.
+    pub high_accuracy: bool,
+    /// Longitude, in degrees.
+    pub longitude: Option<f64>,
+    /// Latitude, in degrees.
+    pub latitude: Option<f64>,
+    /// Course over ground.
+    pub course: Option<f64>,
+    /// True heading.
+    pub heading: Option<u16>,
+    /// Second of the UTC minute the report was generated.
+    pub second: Option<u8>,
+    /// Name of the vessel.
+    pub name: String,
+    /// Type of ship and cargo, named by [SHIP_TYPE](../lookup/static.SHIP_TYPE.html).
+    pub ship_type: u8,
+    /// Dimensions of the vessel.
+    pub dimensions: Dimensions,
+    /// Type of position fixing device, named by
+    /// [POSITION_FIX_DEVICE](../lookup/static.POSITION_FIX_DEVICE.html).
+    pub epfd: u8,
+    /// Whether Receiver Autonomous Integrity Monitoring is in use.
+    pub raim: bool,
+    /// Whether the data terminal equipment is ready.
+    pub dte_ready: bool,
+    /// Whether the unit is in assigned mode rather than autonomous mode.
+    pub assigned: bool,
+}
+
+/// Aid to navigation report, message type 21.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AidToNavigationReport {
+    /// Number of times the message has been repeated.
+    pub repeat: u8,
+    /// MMSI of the aid.
+    pub mmsi: u32,
+    /// Type of aid, named by [ATON_TYPE](../lookup/static.ATON_TYPE.html).
+    pub aid_type: u8,
+    /// Name of the aid, including the extension sent after the fixed part of the message.
+    pub name: String,
+    /// Whether the position is accurate to better than 10 metres.
+    pub high_accuracy: bool,
+    /// Longitude, in degrees.
+    pub longitude: Option<f64>,
+    /// Latitude, in degrees.
+    pub latitude: Option<f64>,
+    /// Dimensions of the aid.
+    pub dimensions: Dimensions,
+    /// Type of position fixing device, named by
+    /// [POSITION_FIX_DEVICE](../lookup/static.POSITION_FIX_DEVICE.html).
+    pub epfd: u8,
+    /// Second of the UTC minute the report was generated.
+    pub second: Option<u8>,
+    /// Whether a floating aid is off its assigned position.
+    pub off_position: bool,
+    /// Whether Receiver Autonomous Integrity Monitoring is in use.
+    pub raim: bool,
+    /// Whether the aid is virtual, with no physical presence.
+    pub virtual_aid: bool,
+    /// Whether the unit is in assigned mode rather than autonomous mode.
+    pub assigned: bool,
+}
+
+/// Class B static data report part A, message type 24.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticDataReportA {
+    /// Number of times the message has been repeated.
+    pub repeat: u8,
+    /// MMSI of the vessel.
+    pub mmsi: u32,
+    /// Name of the vessel.
+    pub name: String,
+}
+
+/// Class B static data report part B, message type 24.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticDataReportB {
+    /// Number of times the message has been repeated.
+    pub repeat: u8,
+    /// MMSI of the vessel.
+    pub mmsi: u32,
+    /// Type of ship and cargo, named by [SHIP_TYPE](../lookup/static.SHIP_TYPE.html).
+    pub ship_type: u8,
+    /// Manufacturer, model and serial number of the unit.
+    pub vendor_id: String,
+    /// Radio call sign.
+    pub callsign: String,
+    /// Dimensions of the vessel. Auxiliary craft send their mothership's MMSI instead.
+    pub dimensions: Dimensions,
+    /// MMSI of the mothership, sent by auxiliary craft.
+    pub mothership_mmsi: Option<u32>,
+}
+
+/// Long range AIS broadcast, message type 27.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LongRangeReport {
+    /// Number of times the message has been repeated.
+    pub repeat: u8,
+    /// MMSI of the vessel.
+    pub mmsi: u32,
+    /// Whether the position is accurate to better than 10 metres.
+    pub high_accuracy: bool,
+    /// Whether Receiver Autonomous Integrity Monitoring is in use.
+    pub raim: bool,
+    /// Navigational status, named by [NAVIGATION_STATUS](../lookup/static.NAVIGATION_STATUS.html).
+    pub navigation_status: u8,
+    /// Longitude, in degrees, to a tenth of a minute.
+    pub longitude: Option<f64>,
+    /// Latitude, in degrees, to a tenth of a minute.
+    pub latitude: Option<f64>,
+    /// Speed over ground, to a knot.
+    pub speed: Option<f64>,
+    /// Course over ground, to a degree.
+    pub course: Option<f64>,
+    /// Whether the position is current rather than from before the message was sent.
+    pub current: bool,
+}
+
+/// A decoded AIS message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    /// Message types 1, 2 and 3.
+    PositionReport(PositionReport),
+    /// Message type 4.
+    BaseStationReport(BaseStationReport),
+    /// Message type 5.
+    StaticAndVoyageData(StaticAndVoyageData),
+    /// Message type 18.
+    ClassBPositionReport(ClassBPositionReport),
+    /// Message type 19.
+    ExtendedClassBPositionReport(ExtendedClassBPositionReport),
+    /// Message type 21.
+    AidToNavigationReport(AidToNavigationReport),
+    /// Message type 24, part A.
+    StaticDataReportA(StaticDataReportA),
+    /// Message type 24, part B.
+    StaticDataReportB(StaticDataReportB),
+    /// Message type 27.
+    LongRangeReport(LongRangeReport),
+}
+
+impl Message {
+    /// MMSI of the station which sent the message.
+    pub fn mmsi(&self) -> u32 {
+        match *self {
+            Message::PositionReport(ref m) => m.mmsi,
+            Message::BaseStationReport(ref m) => m.mmsi,
+            Message::StaticAndVoyageData(ref m) => m.mmsi,
+            Message::ClassBPositionReport(ref m) => m.mmsi,
+            Message::ExtendedClassBPositionReport(ref m) => m.mmsi,
+            Message::AidToNavigationReport(ref m) => m.mmsi,
+            Message::StaticDataReportA(ref m) => m.mmsi,
+            Message::StaticDataReportB(ref m) => m.mmsi,
+            Message::LongRangeReport(ref m) => m.mmsi,
+        }
+    }
+
+    /// Converts a decoded AIS PGN into the message it carries. Returns `None` for PGNs which
+    /// are not AIS messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::ais::{self, Message};
+    /// use libnmea::{decode, encode, registry, CanId, Value};
+    ///
+    /// let definition = &registry::get(129038)[0];
+    /// let data = encode(
+    ///     definition,
+    ///     &[
+    ///         ("Message ID", Value::Integer(1)),
+    ///         ("Repeat Indicator", Value::Lookup(0, None)),
+    ///         ("User ID", Value::Integer(477553000)),
+    ///         ("Longitude", Value::Decimal(-122.3458333)),
+    ///         ("Latitude", Value::Decimal(47.5828333)),
+    ///         ("Position Accuracy", Value::Lookup(0, None)),
+    ///         ("RAIM", Value::Lookup(0, None)),
+    ///         ("Time Stamp", Value::Lookup(15, None)),
+    ///         ("COG", Value::Decimal(0.8901)),
+    ///         ("SOG", Value::Decimal(0.0)),
+    ///         ("Communication State", Value::Integer(149208)),
+    ///         ("Heading", Value::Decimal(3.1590)),
+    ///         ("Rate of Turn", Value::Decimal(0.0)),
+    ///         ("Nav Status", Value::Lookup(5, None)),
+    ///         ("Special Maneuver Indicator", Value::Lookup(0, None)),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let message = decode(CanId::new(4, 129038, 43, 255), &data).unwrap();
+    ///
+    /// let from_nmea0183 = ais::decode("177KQJ5000G?tO`K>RA1wUbN0TKH", 0).unwrap();
+    /// match (Message::from_pgn(&message).unwrap(), from_nmea0183) {
+    ///     (Message::PositionReport(a), Message::PositionReport(b)) => {
+    ///         assert_eq!(a, b);
+    ///         assert!(!a.high_accuracy);
+    ///         assert!(!a.raim);
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn from_pgn(message: &decode::Message) -> Option<Message> {
+        let fields = Fields(message);
+        let repeat = fields.raw("Repeat Indicator").unwrap_or(0) as u8;
+        let mmsi = fields.raw("User ID")? as u32;
+
+        Some(match message.pgn {
+            129038 => Message::PositionReport(PositionReport {
+                message_type: fields.raw("Message ID").unwrap_or(1) as u8,
+                repeat,
+                mmsi,
+                navigation_status: fields.raw("Nav Status").unwrap_or(15) as u8,
+                rate_of_turn: fields
+                    .decimal("Rate of Turn")
+                    .map(|rate| rate.to_degrees() * 60.0),
+                speed: fields.speed("SOG"),
+                high_accuracy: fields.flag("Position Accuracy"),
+                longitude: fields.position("Longitude"),
+                latitude: fields.position("Latitude"),
+                course: fields.course("COG"),
+                heading: fields.heading("Heading"),
+                second: fields.second("Time Stamp"),
+                maneuver: fields.raw("Special Maneuver Indicator").unwrap_or(0) as u8,
+                raim: fields.flag("RAIM"),
+                radio: fields.raw("Communication State").unwrap_or(0) as u32,
+            }),
+            129039 => Message::ClassBPositionReport(ClassBPositionReport {
+                repeat,
+                mmsi,
+                speed: fields.speed("SOG"),
+                high_accuracy: fields.flag("Position Accuracy"),
+                longitude: fields.position("Longitude"),
+                latitude: fields.position("Latitude"),
+                course: fields.course("COG"),
+                heading: fields.heading("Heading"),
+                second: fields.second("Time Stamp"),
+                carrier_sense: fields.flag("Unit type"),
+                display: fields.flag("Integrated Display"),
+                dsc: fields.flag("DSC"),
+                band: fields.flag("Band"),
+                message_22: fields.flag("Can handle Msg 22"),
+                assigned: fields.flag("AIS mode"),
+                raim: fields.flag("RAIM"),
+                radio: fields.raw("Communication State").unwrap_or(0) as u32,
+            }),
+            129040 => Message::ExtendedClassBPositionReport(ExtendedClassBPositionReport {
+                repeat,
+                mmsi,
+                speed: fields.speed("SOG"),
+                high_accuracy: fields.flag("Position Accuracy"),
+                longitude: fields.position("Longitude"),
+                latitude: fields.position("Latitude"),
+                course: fields.course("COG"),
+                heading: fields.heading("True Heading"),
+                second: fields.second("Time Stamp"),
+                name: fields.text("Name"),
+                ship_type: fields.raw("Type of ship").unwrap_or(0) as u8,
+                dimensions: fields.dimensions(
+                    "Length",
+                    "Beam",
+                    "Position reference from Starboard",
+                    "Position reference from Bow",
+                ),
+                epfd: fields.raw("GNSS type").unwrap_or(0) as u8,
+                raim: fields.flag("RAIM"),
+                dte_ready: !fields.flag("DTE"),
+                assigned: fields.flag("AIS mode"),
+            }),
+            129041 => Message::AidToNavigationReport(AidToNavigationReport {
+                repeat,
+                mmsi,
+                aid_type: fields.raw("AtoN Type").unwrap_or(0) as u8,
+                name: fields.text("AtoN Name"),
+                high_accuracy: fields.flag("Position Accuracy"),
+                longitude: fields.position("Longitude"),
+                latitude: fields.position("Latitude"),
+                dimensions: fields.dimensions(
+                    "Length/Diameter",
+                    "Beam/Diameter",
+                    "Position Reference from Starboard Edge",
+                    "Position Reference from True North Facing Edge",
+                ),
+                epfd: fields.raw("Position Fixing Device Type").unwrap_or(0) as u8,
+                second: fields.second("Time Stamp"),
+                off_position: fields.flag("Off Position Indicator"),
+                raim: fields.flag("RAIM"),
+                virtual_aid: fields.flag("Virtual AtoN Flag"),
+                assigned: fields.flag("Assigned Mode Flag"),
+            }),
+            129794 => Message::StaticAndVoyageData(StaticAndVoyageData {
+                repeat,
+                mmsi,
+                ais_version: fields.raw("AIS version indicator").unwrap_or(0) as u8,
+                imo: fields
+                    .raw("IMO number")
+                    .and_then(|imo| non_zero(imo as u32)),
+                callsign: fields.text("Callsign"),
+                name: fields.text("Name"),
+                ship_type: fields.raw("Type of ship").unwrap_or(0) as u8,
+                dimensions: fields.dimensions(
+                    "Length",
+                    "Beam",
+                    "Position reference from Starboard",
+                    "Position reference from Bow",
+                ),
+                epfd: fields.raw("GNSS type").unwrap_or(0) as u8,
+                eta: fields.eta("ETA Date", "ETA Time"),
+                draught: fields
+                    .decimal("Draft")
+                    .and_then(|draught| rounded(draught, 10.0).filter(|&d| d > 0.0)),
+                destination: fields.text("Destination"),
+                dte_ready: !fields.flag("DTE"),
+            }),
+            129809 => Message::StaticDataReportA(StaticDataReportA {
+                repeat,
+                mmsi,
+                name: fields.text("Name"),
+            }),
+            129810 => Message::StaticDataReportB(StaticDataReportB {
+                repeat,
+                mmsi,
+                ship_type: fields.raw("Type of ship").unwrap_or(0) as u8,
+                vendor_id: fields.text("Vendor ID"),
+                callsign: fields.text("Callsign"),
+                dimensions: fields.dimensions(
+                    "Length",
+                    "Beam",
+                    "Position reference from Starboard",
+                    "Position reference from Bow",
+                ),
+                mothership_mmsi: fields
+                    .raw("Mothership User ID")
+                    .and_then(|mmsi| non_zero(mmsi as u32)),
+            }),
+            _ => return None,
+        })
+    }
+}
+
+/// The fields of a decoded AIS PGN, read in the units AIS uses.
+struct Fields<'a>(&'a decode::Message);
+
+impl<'a> Fields<'a> {
+    /// The raw value of an integer or lookup field.
+    fn raw(&self, name: &str) -> Option<u64> {
+        match *self.0.get(name)? {
+            Value::Integer(value) => Some(value as u64),
+            Value::Lookup(value, _) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn decimal(&self, name: &str) -> Option<f64> {
+        match *self.0.get(name)? {
+            Value::Decimal(value) => Some(value),
+            Value::Integer(value) => Some(value as f64),
+            _ => None,
+        }
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.raw(name) == Some(1)
+    }
+
+    fn text(&self, name: &str) -> String {
+        match self.0.get(name) {
+            Some(Value::String(text)) => text.clone(),
+            _ => String::new(),
+        }
+    }
+
+    /// A latitude or longitude, to a ten thousandth of a minute.
+    fn position(&self, name: &str) -> Option<f64> {
+        rounded(self.decimal(name)?, 600_000.0)
+    }
+
+    /// A speed in metres per second, in knots to a tenth of a knot.
+    fn speed(&self, name: &str) -> Option<f64> {
+        rounded(self.decimal(name)? * 3600.0 / 1852.0, 10.0)
+    }
+
+    /// An angle in radians, in degrees to a tenth of a degree.
+    fn course(&self, name: &str) -> Option<f64> {
+        rounded(self.decimal(name)?.to_degrees(), 10.0)
+    }
+
+    /// An angle in radians, in whole degrees.
+    fn heading(&self, name: &str) -> Option<u16> {
+        rounded(self.decimal(name)?.to_degrees(), 1.0).map(|heading| heading as u16 % 360)
+    }
+
+    fn second(&self, name: &str) -> Option<u8> {
+        below(self.raw(name)? as u32, 60)
+    }
+
+    /// Dimensions sent as overall sizes and the position of the reference point from the
+    /// starboard side and the bow.
+    fn dimensions(&self, length: &str, beam: &str, starboard: &str, bow: &str) -> Dimensions {
+        let metres = |name| {
+            self.decimal(name)
+                .and_then(|value| rounded(value, 1.0))
+                .map(|value| value as u16)
+        };
+        let (length, beam) = (metres(length), metres(beam));
+        let (starboard, bow) = (metres(starboard), metres(bow));
+        let remainder = |total: Option<u16>, part: Option<u16>| {
+            total.and_then(|total| total.checked_sub(part?))
+        };
+
+        Dimensions {
+            to_bow: bow.filter(|&v| v > 0),
+            to_stern: remainder(length, bow).filter(|&v| v > 0),
+            to_port: remainder(beam, starboard).filter(|&v| v > 0),
+            to_starboard: starboard.filter(|&v| v > 0),
+        }
+    }
+
+    /// An estimated time of arrival sent as days since the Unix epoch and seconds since midnight.
+    fn eta(&self, date: &str, time: &str) -> Eta {
+        let date = self
+            .raw(date)
+            .map(|days| calendar::from_epoch(Duration::from_secs(days * 86_400)));
+        let time = self.decimal(time).map(|seconds| seconds as u32);
+
+        Eta {
+            month: date.map(|(_, month, _, _)| month as u8),
+            day: date.map(|(_, _, day, _)| day as u8),
+            hour: time.and_then(|seconds| below(seconds / 3600, 24)),
+            minute: time.map(|seconds| (seconds / 60 % 60) as u8),
+        }
+    }
+}
+
+/// Rounds a value to a fraction of a unit, or `None` if it is not finite.
+fn rounded(value: f64, per_unit: f64) -> Option<f64> {
+    if value.is_finite() {
+        Some((value * per_unit).round() / per_unit)
+    } else {
+        None
+    }
+}
+
+/// Decodes the armored payload of a complete message.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::ais::{decode, Message};
+///
+/// match decode("177KQJ5000G?tO`K>RA1wUbN0TKH", 0).unwrap() {
+///     Message::PositionReport(report) => {
+///         assert_eq!(report.mmsi, 477553000);
+///         assert_eq!(report.navigation_status, 5);
+///         assert_eq!(report.speed, Some(0.0));
+///         assert_eq!(report.course, Some(51.0));
+///         assert_eq!(report.heading, Some(181));
+///         assert_eq!(report.second, Some(15));
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn decode(payload: &str, fill_bits: u8) -> Result<Message, AisError> {
+    let bits = Bits::dearmor(payload, fill_bits)?;
+    let message_type = bits.unsigned(0, 6) as u8;
+
+    Ok(match message_type {
+        1..=3 => {
+            bits.require(168)?;
+            Message::PositionReport(PositionReport {
+                message_type,
+                repeat: bits.repeat(),
+                mmsi: bits.mmsi(),
+                navigation_status: bits.unsigned(38, 4) as u8,
+                rate_of_turn: rate_of_turn(bits.signed(42, 8)),
+                speed: speed(bits.unsigned(50, 10)),
+                high_accuracy: bits.flag(60),
+                longitude: longitude(bits.signed(61, 28), 600_000.0),
+                latitude: latitude(bits.signed(89, 27), 600_000.0),
+                course: course(bits.unsigned(116, 12)),
+                heading: heading(bits.unsigned(128, 9)),
+                second: second(bits.unsigned(137, 6)),
+                maneuver: bits.unsigned(143, 2) as u8,
+                raim: bits.flag(148),
+                radio: bits.unsigned(149, 19),
+            })
+        }
+        4 => {
+            bits.require(168)?;
+            Message::BaseStationReport(BaseStationReport {
+                repeat: bits.repeat(),
+                mmsi: bits.mmsi(),
+                date: date(
+                    bits.unsigned(38, 14),
+                    bits.unsigned(52, 4),
+                    bits.unsigned(56, 5),
+                ),
+                time: time(
+                    bits.unsigned(61, 5),
+                    bits.unsigned(66, 6),
+                    bits.unsigned(72, 6),
+                ),
+                high_accuracy: bits.flag(78),
+                longitude: longitude(bits.signed(79, 28), 600_000.0),
+                latitude: latitude(bits.signed(107, 27), 600_000.0),
+                epfd: bits.unsigned(134, 4) as u8,
+                raim: bits.flag(148),
+                radio: bits.unsigned(149, 19),
+            })
+        }
+        5 => {
+            bits.require(420)?;
+            Message::StaticAndVoyageData(StaticAndVoyageData {
+                repeat: bits.repeat(),
+                mmsi: bits.mmsi(),
+                ais_version: bits.unsigned(38, 2) as u8,
+                imo: non_zero(bits.unsigned(40, 30)),
+                callsign: bits.text(70, 7),
+                name: bits.text(112, 20),
+                ship_type: bits.unsigned(232, 8) as u8,
+                dimensions: bits.dimensions(240),
+                epfd: bits.unsigned(270, 4) as u8,
+                eta: Eta {
+                    month: non_zero(bits.unsigned(274, 4)).map(|v| v as u8),
+                    day: non_zero(bits.unsigned(278, 5)).map(|v| v as u8),
+                    hour: below(bits.unsigned(283, 5), 24),
+                    minute: below(bits.unsigned(288, 6), 60),
+                },
+                draught: non_zero(bits.unsigned(294, 8)).map(|v| f64::from(v) / 10.0),
+                destination: bits.text(302, 20),
+                dte_ready: !bits.flag(422),
+            })
+        }
+        18 => {
+            bits.require(168)?;
+            Message::ClassBPositionReport(ClassBPositionReport {
+                repeat: bits.repeat(),
+                mmsi: bits.mmsi(),
+                speed: speed(bits.unsigned(46, 10)),
+                high_accuracy: bits.flag(56),
+                longitude: longitude(bits.signed(57, 28), 600_000.0),
+                latitude: latitude(bits.signed(85, 27), 600_000.0),
+                course: course(bits.unsigned(112, 12)),
+                heading: heading(bits.unsigned(124, 9)),
+                second: second(bits.unsigned(133, 6)),
+                carrier_sense: bits.flag(141),
+                display: bits.flag(142),
+                dsc: bits.flag(143),
+                band: bits.flag(144),
+                message_22: bits.flag(145),
+                assigned: bits.flag(146),
+                raim: bits.flag(147),
+                radio: bits.unsigned(148, 20),
+            })
+        }
+        19 => {
+            bits.require(312)?;
+            Message::ExtendedClassBPositionReport(ExtendedClassBPositionReport {
+                repeat: bits.repeat(),
+                mmsi: bits.mmsi(),
+                speed: speed(bits.unsigned(46, 10)),
+                high_accuracy: bits.flag(56),
+                longitude: longitude(bits.signed(57, 28), 600_000.0),
+                latitude: latitude(bits.signed(85, 27), 600_000.0),
+                course: course(bits.unsigned(112, 12)),
+                heading: heading(bits.unsigned(124, 9)),
+                second: second(bits.unsigned(133, 6)),
+                name: bits.text(143, 20),
+                ship_type: bits.unsigned(263, 8) as u8,
+                dimensions: bits.dimensions(271),
+                epfd: bits.unsigned(301, 4) as u8,
+                raim: bits.flag(305),
+                dte_ready: !bits.flag(306),
+                assigned: bits.flag(307),
+            })
+        }
+        21 => {
+            bits.require(272)?;
+            let mut name = bits.text(43, 20);
+            // The extension is whole characters, followed by padding to a byte boundary.
+            name.push_str(&bits.text(272, (bits.len - 272) / 6));
+            Message::AidToNavigationReport(AidToNavigationReport {
+                repeat: bits.repeat(),
+                mmsi: bits.mmsi(),
+                aid_type: bits.unsigned(38, 5) as u8,
+                name,
+                high_accuracy: bits.flag(163),
+                longitude: longitude(bits.signed(164, 28), 600_000.0),
+                latitude: latitude(bits.signed(192, 27), 600_000.0),
+                dimensions: bits.dimensions(219),
+                epfd: bits.unsigned(249, 4) as u8,
+                second: second(bits.unsigned(253, 6)),
+                off_position: bits.flag(259),
+                raim: bits.flag(268),
+                virtual_aid: bits.flag(269),
+                assigned: bits.flag(270),
+            })
+        }
+        24 => match bits.unsigned(38, 2) {
+            0 => {
+                bits.require(160)?;
+                Message::StaticDataReportA(StaticDataReportA {
+                    repeat: bits.repeat(),
+                    mmsi: bits.mmsi(),
+                    name: bits.text(40, 20),
+                })
+            }
+            1 => {
+                bits.require(162)?;
+                let mmsi = bits.mmsi();
+                // Auxiliary craft have MMSIs of the form 98XXXYYYY.
+                let auxiliary = mmsi / 10_000_000 == 98;
+                Message::StaticDataReportB(StaticDataReportB {
+                    repeat: bits.repeat(),
+                    mmsi,
+                    ship_type: bits.unsigned(40, 8) as u8,
+                    vendor_id: bits.text(48, 7),
+                    callsign: bits.text(90, 7),
+                    dimensions: if auxiliary {
+                        Dimensions {
+                            to_bow: None,
+                            to_stern: None,
+                            to_port: None,
+                            to_starboard: None,
+                        }
+                    } else {
+                        bits.dimensions(132)
+                    },
+                    mothership_mmsi: if auxiliary {
+                        non_zero(bits.unsigned(132, 30))
+                    } else {
+                        None
+                    },
+                })
+            }
+            _ => return Err(AisError::UnsupportedMessage(message_type)),
+        },
+        27 => {
+            bits.require(96)?;
+            Message::LongRangeReport(LongRangeReport {
+                repeat: bits.repeat(),
+                mmsi: bits.mmsi(),
+                high_accuracy: bits.flag(38),
+                raim: bits.flag(39),
+                navigation_status: bits.unsigned(40, 4) as u8,
+                longitude: longitude(bits.signed(44, 18), 600.0),
+                latitude: latitude(bits.signed(62, 17), 600.0),
+                speed: below(bits.unsigned(79, 6), 63).map(f64::from),
+                course: below(bits.unsigned(85, 9), 360).map(f64::from),
+                current: !bits.flag(94),
+            })
+        }
+        _ => return Err(AisError::UnsupportedMessage(message_type)),
+    })
+}
+
+/// The bits of a message, most significant first.
+struct Bits {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl Bits {
+    /// Removes the six bit armoring from a payload.
+    fn dearmor(payload: &str, fill_bits: u8) -> Result<Bits, AisError> {
+        let mut bytes = vec![0; (payload.len() * 6).div_ceil(8)];
+        for (i, c) in payload.chars().enumerate() {
+            let value = match c {
+                '0'..='W' => c as u8 - b'0',
+                '`'..='w' => c as u8 - b'0' - 8,
+                _ => return Err(AisError::Armoring(c)),
+            };
+            for bit in 0..6 {
+                if value & (0x20 >> bit) != 0 {
+                    let index = i * 6 + bit;
+                    bytes[index / 8] |= 0x80 >> (index % 8);
+                }
+            }
+        }
+
+        let len = payload.len() * 6;
+        if fill_bits > 5 || fill_bits as usize > len {
+            return Err(AisError::FillBits(fill_bits));
+        }
+
+        Ok(Bits {
+            bytes,
+            len: len - fill_bits as usize,
+        })
+    }
+
+    fn require(&self, len: usize) -> Result<(), AisError> {
+        if self.len < len {
+            return Err(AisError::Length);
+        }
+
+        Ok(())
+    }
+
+    /// Reads up to 32 bits. Bits past the end of the message read as zero.
+    fn unsigned(&self, start: usize, size: usize) -> u32 {
+        (start..start + size).fold(0, |value, index| {
+            let bit = index < self.len && self.bytes[index / 8] & (0x80 >> (index % 8)) != 0;
+            value << 1 | bit as u32
+        })
+    }
+
+    fn signed(&self, start: usize, size: usize) -> i32 {
+        let shift = 32 - size;
+        ((self.unsigned(start, size) << shift) as i32) >> shift
+    }
+
+    fn flag(&self, index: usize) -> bool {
+        self.unsigned(index, 1) == 1
+    }
+
+    /// Reads six bit ASCII, ending at the first `@` and without trailing spaces.
+    fn text(&self, start: usize, chars: usize) -> String {
+        let text: String = (0..chars)
+            .map(|i| {
+                let value = self.unsigned(start + i * 6, 6) as u8;
+                (if value < 32 { value + 64 } else { value }) as char
+            })
+            .take_while(|&c| c != '@')
+            .collect();
+        text.trim_end().to_string()
+    }
+
+    fn repeat(&self) -> u8 {
+        self.unsigned(6, 2) as u8
+    }
+
+    fn mmsi(&self) -> u32 {
+        self.unsigned(8, 30)
+    }
+
+    fn dimensions(&self, start: usize) -> Dimensions {
+        Dimensions {
+            to_bow: non_zero(self.unsigned(start, 9)).map(|v| v as u16),
+            to_stern: non_zero(self.unsigned(start + 9, 9)).map(|v| v as u16),
+            to_port: non_zero(self.unsigned(start + 18, 6)).map(|v| v as u16),
+            to_starboard: non_zero(self.unsigned(start + 24, 6)).map(|v| v as u16),
+        }
+    }
+}
+
+fn non_zero(value: u32) -> Option<u32> {
+    if value == 0 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn below(value: u32, limit: u32) -> Option<u8> {
+    if value < limit {
+        Some(value as u8)
+    } else {
+        None
+    }
+}
+
+/// Rate of turn sent as 4.733 times the square root of the rate in degrees per minute.
+fn rate_of_turn(raw: i32) -> Option<f64> {
+    if raw == -128 {
+        return None;
+    }
+
+    let rate = (f64::from(raw) / 4.733).powi(2);
+    Some(if raw < 0 { -rate } else { rate })
+}
+
+fn speed(raw: u32) -> Option<f64> {
+    if raw == 1023 {
+        None
+    } else {
+        Some(f64::from(raw) / 10.0)
+    }
+}
+
+fn course(raw: u32) -> Option<f64> {
+    if raw >= 3600 {
+        None
+    } else {
+        Some(f64::from(raw) / 10.0)
+    }
+}
+
+fn heading(raw: u32) -> Option<u16> {
+    if raw >= 360 {
+        None
+    } else {
+        Some(raw as u16)
+    }
+}
+
+fn second(raw: u32) -> Option<u8> {
+    below(raw, 60)
+}
+
+/// Longitude sent in units of `per_degree`, with 181 degrees meaning not available.
+fn longitude(raw: i32, per_degree: f64) -> Option<f64> {
+    let degrees = f64::from(raw) / per_degree;
+    if degrees.abs() > 180.0 {
+        None
+    } else {
+        Some(degrees)
+    }
+}
+
+/// Latitude sent in units of `per_degree`, with 91 degrees meaning not available.
+fn latitude(raw: i32, per_degree: f64) -> Option<f64> {
+    let degrees = f64::from(raw) / per_degree;
+    if degrees.abs() > 90.0 {
+        None
+    } else {
+        Some(degrees)
+    }
+}
+
+fn date(year: u32, month: u32, day: u32) -> Option<Date> {
+    if year == 0 || !calendar::is_valid_date(i64::from(year), month, day) {
+        return None;
+    }
+
+    Some(Date {
+        year: i64::from(year),
+        month,
+        day,
+    })
+}
+
+fn time(hour: u32, minute: u32, second: u32) -> Option<Duration> {
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    Some(Duration::from_secs(u64::from(
+        (hour * 60 + minute) * 60 + second,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Armors fields given as value and width in bits, returning the payload and fill bits.
+    fn armor(fields: &[(u32, usize)]) -> (String, u8) {
+        let mut bits: Vec<bool> = fields
+            .iter()
+            .flat_map(|&(value, size)| {
+                (0..size)
+                    .rev()
+                    .map(move |bit| value.checked_shr(bit as u32).unwrap_or(0) & 1 == 1)
+            })
+            .collect();
+        let fill = (6 - bits.len() % 6) % 6;
+        bits.resize(bits.len() + fill, false);
+
+        let payload = bits
+            .chunks(6)
+            .map(|chunk| {
+                let value = chunk.iter().fold(0u8, |value, &bit| value << 1 | bit as u8);
+                (if value < 40 { value + 48 } else { value + 56 }) as char
+            })
+            .collect();
+        (payload, fill as u8)
+    }
+
+    #[test]
+    fn armoring() {
+        assert_eq!(
+            decode("13aEOK?P00PD2wVMdLDRhgvL289?", 0).unwrap().mmsi(),
+            244670316
+        );
+        assert_eq!(decode("1X", 0), Err(AisError::Armoring('X')));
+        assert_eq!(decode("1\u{e9}", 0), Err(AisError::Armoring('\u{e9}')));
+        assert_eq!(decode("1 ", 0), Err(AisError::Armoring(' ')));
+    }
+
+    #[test]
+    fn fill_bits() {
+        let (payload, _) = armor(&[(27, 6), (0, 90)]);
+
+        assert!(decode(&payload, 0).is_ok());
+        assert_eq!(decode(&payload, 6), Err(AisError::FillBits(6)));
+        assert_eq!(decode("", 1), Err(AisError::FillBits(1)));
+    }
+
+    #[test]
+    fn too_short() {
+        let (payload, fill) = armor(&[(1, 6), (0, 161)]);
+        assert_eq!(decode(&payload, fill), Err(AisError::Length));
+
+        let (payload, fill) = armor(&[(27, 6), (0, 89)]);
+        assert_eq!(decode(&payload, fill), Err(AisError::Length));
+
+        assert_eq!(decode("", 0), Err(AisError::UnsupportedMessage(0)));
+    }
+
+    #[test]
+    fn unsupported_messages() {
+        let (payload, fill) = armor(&[(6, 6), (0, 162)]);
+        assert_eq!(decode(&payload, fill), Err(AisError::UnsupportedMessage(6)));
+
+        // Message 24 has only parts A and B.
+        let (payload, fill) = armor(&[(24, 6), (0, 32), (2, 2), (0, 128)]);
+        assert_eq!(
+            decode(&payload, fill),
+            Err(AisError::UnsupportedMessage(24))
+        );
+    }
+
+    #[test]
+    fn position_not_available() {
+        let (payload, fill) = armor(&[
+            (1, 6),
+            (0, 2),
+            (123_456_789, 30),
+            (15, 4),
+            (0x80, 8),
+            (1023, 10),
+            (0, 1),
+            (181 * 600_000, 28),
+            (91 * 600_000, 27),
+            (3600, 12),
+            (511, 9),
+            (60, 6),
+            (0, 25),
+        ]);
+
+        match decode(&payload, fill).unwrap() {
+            Message::PositionReport(report) => {
+                assert_eq!(report.mmsi, 123_456_789);
+                assert_eq!(report.navigation_status, 15);
+                assert_eq!(report.rate_of_turn, None);
+                assert_eq!(report.speed, None);
+                assert_eq!(report.longitude, None);
+                assert_eq!(report.latitude, None);
+                assert_eq!(report.course, None);
+                assert_eq!(report.heading, None);
+                assert_eq!(report.second, None);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn negative_values() {
+        let (payload, fill) = armor(&[
+            (1, 6),
+            (0, 32),
+            (0, 4),
+            (-10i32 as u32 & 0xff, 8),
+            (0, 11),
+            (-60_000i32 as u32 & 0x0fff_ffff, 28),
+            (-30_000i32 as u32 & 0x07ff_ffff, 27),
+            (0, 52),
+        ]);
+
+        match decode(&payload, fill).unwrap() {
+            Message::PositionReport(report) => {
+                assert!(report.rate_of_turn.unwrap() < 0.0);
+                assert_eq!(report.longitude, Some(-0.1));
+                assert_eq!(report.latitude, Some(-0.05));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn base_station_date_and_time() {
+        let report = |year, month, day, hour, minute, second| {
+            let (payload, fill) = armor(&[
+                (4, 6),
+                (0, 32),
+                (year, 14),
+                (month, 4),
+                (day, 5),
+                (hour, 5),
+                (minute, 6),
+                (second, 6),
+                (0, 96),
+            ]);
+            match decode(&payload, fill).unwrap() {
+                Message::BaseStationReport(report) => (report.date, report.time),
+                other => panic!("unexpected {:?}", other),
+            }
+        };
+
+        assert_eq!(
+            report(2024, 2, 29, 23, 59, 59),
+            (
+                Some(Date {
+                    year: 2024,
+                    month: 2,
+                    day: 29
+                }),
+                Some(Duration::from_secs(86_399))
+            )
+        );
+        assert_eq!(report(0, 1, 1, 24, 0, 0), (None, None));
+        assert_eq!(report(2023, 2, 29, 0, 60, 0).0, None);
+        assert_eq!(report(2023, 13, 1, 0, 0, 60), (None, None));
+        assert_eq!(report(2023, 0, 0, 0, 0, 0).0, None);
+    }
+
+    #[test]
+    fn text_ends_at_padding() {
+        let bits = Bits {
+            bytes: vec![0x04, 0x10, 0x00],
+            len: 24,
+        };
+
+        assert_eq!(bits.text(0, 4), "AA");
+        assert_eq!(bits.text(0, 2), "AA");
+    }
+
+    fn message(pgn: u32, fields: Vec<(&'static str, Value)>) -> decode::Message {
+        decode::Message {
+            name: "",
+            pgn,
+            priority: 4,
+            source: 43,
+            destination: 255,
+            fields: fields
+                .into_iter()
+                .map(|(name, value)| decode::FieldValue {
+                    name,
+                    unit: None,
+                    value,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn from_pgn_without_ais_message() {
+        let mmsi = || vec![("User ID", Value::Integer(477553000))];
+
+        assert_eq!(Message::from_pgn(&message(129025, mmsi())), None);
+        assert_eq!(Message::from_pgn(&message(129038, vec![])), None);
+        assert_eq!(
+            Message::from_pgn(&message(129038, vec![("User ID", Value::NotAvailable)])),
+            None
+        );
+        assert!(Message::from_pgn(&message(129038, mmsi())).is_some());
+    }
+
+    #[test]
+    fn from_pgn_not_available() {
+        use {encode, registry, CanId};
+
+        let data = encode(
+            &registry::get(129038)[0],
+            &[("User ID", Value::Integer(477553000))],
+        )
+        .unwrap();
+        let message = decode::decode(CanId::new(4, 129038, 43, 255), &data).unwrap();
+
+        match Message::from_pgn(&message).unwrap() {
+            Message::PositionReport(report) => {
+                assert_eq!(report.mmsi, 477553000);
+                assert_eq!(report.rate_of_turn, None);
+                assert_eq!(report.speed, None);
+                assert_eq!(report.longitude, None);
+                assert_eq!(report.latitude, None);
+                assert_eq!(report.course, None);
+                assert_eq!(report.heading, None);
+                assert_eq!(report.second, None);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn from_pgn_dimensions() {
+        let report = |fields: Vec<(&'static str, Value)>| {
+            let mut fields = fields;
+            fields.push(("User ID", Value::Integer(351759000)));
+            match Message::from_pgn(&message(129794, fields)).unwrap() {
+                Message::StaticAndVoyageData(data) => data,
+                other => panic!("unexpected {:?}", other),
+            }
+        };
+
+        let data = report(vec![
+            ("Length", Value::Decimal(100.0)),
+            ("Beam", Value::Decimal(20.0)),
+            ("Position reference from Starboard", Value::Decimal(5.0)),
+            ("Position reference from Bow", Value::Decimal(30.0)),
+            ("IMO number", Value::Integer(0)),
+            ("Draft", Value::Decimal(0.0)),
+        ]);
+        assert_eq!(
+            data.dimensions,
+            Dimensions {
+                to_bow: Some(30),
+                to_stern: Some(70),
+                to_port: Some(15),
+                to_starboard: Some(5),
+            }
+        );
+        assert_eq!(data.imo, None);
+        assert_eq!(data.draught, None);
+        assert_eq!(data.eta.month, None);
+        assert!(data.dte_ready);
+
+        // A reference point outside the vessel leaves the far side unknown.
+        let data = report(vec![
+            ("Length", Value::Decimal(10.0)),
+            ("Position reference from Bow", Value::Decimal(30.0)),
+        ]);
+        assert_eq!(data.dimensions.to_bow, Some(30));
+        assert_eq!(data.dimensions.to_stern, None);
+        assert_eq!(data.dimensions.to_port, None);
+    }
+}
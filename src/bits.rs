@@ -0,0 +1,244 @@
+//! Bit level access to NMEA 2000 payloads.
+//!
+//! Fields are packed little-endian with no regard for byte or word boundaries, so a field may
+//! start part way through one byte and end part way through another, including across a 64-bit
+//! boundary in a payload reassembled from a Fast Packet or transport protocol transfer.
+
+/// Reads fields out of a payload of any length.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::bits::BitReader;
+///
+/// let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf0, 0x0f, 0x00];
+/// let reader = BitReader::new(&data);
+///
+/// // 8 bits straddling the 64-bit boundary.
+/// assert_eq!(reader.read_unsigned(60, 8), Some(0xff));
+/// assert_eq!(reader.read_signed(64, 12), Some(0x00f));
+/// assert_eq!(reader.read_signed(0, 16), Some(-1));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BitReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a reader over `data`.
+    pub fn new(data: &'a [u8]) -> BitReader<'a> {
+        BitReader { data }
+    }
+
+    /// Length of the payload in bits.
+    pub fn bit_len(&self) -> usize {
+        self.data.len() * 8
+    }
+
+    /// Reads an unsigned value of `size` bits starting `start` bits into the payload. Returns
+    /// `None` if `size` is 0 or over 64, or if the field does not fit in the payload.
+    pub fn read_unsigned(&self, start: usize, size: usize) -> Option<u64> {
+        if size == 0 || size > 64 || start + size > self.bit_len() {
+            return None;
+        }
+
+        // At most 9 bytes are spanned by a 64-bit field, which always fits in 128 bits.
+        let first = start / 8;
+        let last = (start + size).div_ceil(8);
+        let word = self.data[first..last]
+            .iter()
+            .rev()
+            .fold(0u128, |word, &byte| (word << 8) | u128::from(byte));
+
+        Some(((word >> (start % 8)) as u64) & mask(size))
+    }
+
+    /// Reads a two's complement signed value of `size` bits starting `start` bits into the
+    /// payload. Returns `None` under the same conditions as
+    /// [read_unsigned](#method.read_unsigned).
+    pub fn read_signed(&self, start: usize, size: usize) -> Option<i64> {
+        let raw = self.read_unsigned(start, size)?;
+        let shift = 64 - size;
+        Some(((raw << shift) as i64) >> shift)
+    }
+
+    /// Returns the bytes of a byte aligned field of `size` bits. Returns `None` if the field is
+    /// not byte aligned or does not fit in the payload.
+    pub fn read_bytes(&self, start: usize, size: usize) -> Option<&'a [u8]> {
+        if start % 8 != 0 || size % 8 != 0 || start + size > self.bit_len() {
+            return None;
+        }
+
+        Some(&self.data[start / 8..(start + size) / 8])
+    }
+}
+
+/// A mask of the low `size` bits.
+pub fn mask(size: usize) -> u64 {
+    if size >= 64 {
+        !0
+    } else {
+        (1 << size) - 1
+    }
+}
+
+/// Writes fields into a payload, the counterpart of [BitReader](struct.BitReader.html).
+///
+/// The payload starts out filled with ones, which is what NMEA 2000 expects in reserved bits and
+/// in fields which are not written. It grows as needed to hold fields written past its end.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::bits::BitWriter;
+///
+/// let mut writer = BitWriter::new(2);
+/// writer.write_unsigned(4, 8, 0x00);
+///
+/// assert_eq!(writer.into_bytes(), vec![0x0f, 0xf0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitWriter {
+    data: Vec<u8>,
+}
+
+impl BitWriter {
+    /// Creates a writer over a payload of `len` bytes.
+    pub fn new(len: usize) -> BitWriter {
+        BitWriter {
+            data: vec![0xff; len],
+        }
+    }
+
+    /// Length of the payload in bits.
+    pub fn bit_len(&self) -> usize {
+        self.data.len() * 8
+    }
+
+    /// Writes the low `size` bits of `value` starting `start` bits into the payload.
+    pub fn write_unsigned(&mut self, start: usize, size: usize, value: u64) {
+        let size = size.min(64);
+        self.reserve(start + size);
+
+        for bit in 0..size {
+            let position = start + bit;
+            let byte = &mut self.data[position / 8];
+            if value >> bit & 1 == 1 {
+                *byte |= 1 << (position % 8);
+            } else {
+                *byte &= !(1 << (position % 8));
+            }
+        }
+    }
+
+    /// Writes `value` as a two's complement signed value of `size` bits.
+    pub fn write_signed(&mut self, start: usize, size: usize, value: i64) {
+        self.write_unsigned(start, size, value as u64 & mask(size));
+    }
+
+    /// Writes `bytes` starting `start` bits into the payload.
+    pub fn write_bytes(&mut self, start: usize, bytes: &[u8]) {
+        for (i, &byte) in bytes.iter().enumerate() {
+            self.write_unsigned(start + i * 8, 8, u64::from(byte));
+        }
+    }
+
+    /// Returns the payload.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+
+    fn reserve(&mut self, bits: usize) {
+        let len = bits.div_ceil(8);
+        if len > self.data.len() {
+            self.data.resize(len, 0xff);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn out_of_bounds() {
+        let data = [0xff; 8];
+        let reader = BitReader::new(&data);
+
+        assert_eq!(reader.read_unsigned(0, 0), None);
+        assert_eq!(reader.read_unsigned(0, 65), None);
+        assert_eq!(reader.read_unsigned(57, 8), None);
+        assert_eq!(reader.read_unsigned(64, 1), None);
+        assert_eq!(reader.read_signed(60, 5), None);
+        assert_eq!(reader.read_unsigned(0, 64), Some(!0));
+        assert_eq!(reader.read_unsigned(63, 1), Some(1));
+    }
+
+    #[test]
+    fn unaligned_bytes() {
+        let data = [1, 2, 3, 4];
+        let reader = BitReader::new(&data);
+
+        assert_eq!(reader.read_bytes(8, 16), Some(&data[1..3]));
+        assert_eq!(reader.read_bytes(4, 16), None);
+        assert_eq!(reader.read_bytes(8, 12), None);
+        assert_eq!(reader.read_bytes(16, 24), None);
+        assert_eq!(reader.read_bytes(32, 0), Some(&[] as &[u8]));
+    }
+
+    #[test]
+    fn signed_extremes() {
+        let data = [0x80, 0x7f];
+        let reader = BitReader::new(&data);
+
+        assert_eq!(reader.read_signed(0, 8), Some(-128));
+        assert_eq!(reader.read_signed(8, 8), Some(127));
+        assert_eq!(reader.read_signed(0, 1), Some(0));
+        assert_eq!(reader.read_signed(7, 1), Some(-1));
+
+        let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f];
+        assert_eq!(BitReader::new(&data).read_signed(0, 64), Some(i64::MAX));
+    }
+
+    #[test]
+    fn round_trip_every_offset_and_size() {
+        for size in 1..=64 {
+            for start in 0..=72 - size {
+                let value = 0x8123_4567_89ab_cdef & mask(size);
+                let mut writer = BitWriter::new(9);
+                writer.write_unsigned(start, size, value);
+                let data = writer.into_bytes();
+                let reader = BitReader::new(&data);
+
+                assert_eq!(reader.read_unsigned(start, size), Some(value));
+                if start > 0 {
+                    assert_eq!(reader.read_unsigned(0, start.min(64)), Some(mask(start)));
+                }
+                if start + size < 72 {
+                    let rest = (72 - start - size).min(64);
+                    assert_eq!(reader.read_unsigned(start + size, rest), Some(mask(rest)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn signed_round_trip() {
+        for &(size, value) in &[(8, -128), (8, 127), (12, -1), (16, -32768), (64, i64::MIN)] {
+            let mut writer = BitWriter::new(0);
+            writer.write_signed(3, size, value);
+            let data = writer.into_bytes();
+
+            assert_eq!(BitReader::new(&data).read_signed(3, size), Some(value));
+        }
+    }
+
+    #[test]
+    fn writer_grows() {
+        let mut writer = BitWriter::new(1);
+        writer.write_bytes(12, &[0x12, 0x34]);
+
+        assert_eq!(writer.bit_len(), 32);
+        assert_eq!(writer.into_bytes(), vec![0xff, 0x2f, 0x41, 0xf3]);
+    }
+}
@@ -0,0 +1,113 @@
+//! Conversions between UTC calendar dates and times since the Unix epoch.
+
+use std::time::Duration;
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date of a number of days since 1970-01-01 as year, month and day.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Whether a date exists in the proleptic Gregorian calendar.
+pub fn is_valid_date(year: i64, month: u32, day: u32) -> bool {
+    let leap = year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return false,
+    };
+    (1..=days).contains(&day)
+}
+
+/// Time since the Unix epoch of a UTC date and time of day. Returns `None` for invalid dates and
+/// times, and for times before the epoch.
+pub fn to_epoch(year: i64, month: u32, day: u32, time_of_day: Duration) -> Option<Duration> {
+    if !is_valid_date(year, month, day) || time_of_day.as_secs() >= 86_400 {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    if days < 0 {
+        return None;
+    }
+
+    Some(Duration::from_secs(days as u64 * 86_400) + time_of_day)
+}
+
+/// Splits a time since the Unix epoch into a UTC date and time of day.
+pub fn from_epoch(time: Duration) -> (i64, u32, u32, Duration) {
+    let days = time.as_secs() / 86_400;
+    let (year, month, day) = civil_from_days(days as i64);
+    (year, month, day, time - Duration::from_secs(days * 86_400))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_round_trip() {
+        for days in -800_000..800_000 {
+            let (year, month, day) = civil_from_days(days);
+            assert!(is_valid_date(year, month, day));
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_valid_date(2024, 2, 29));
+        assert!(is_valid_date(2000, 2, 29));
+        assert!(!is_valid_date(2023, 2, 29));
+        assert!(!is_valid_date(1900, 2, 29));
+    }
+
+    #[test]
+    fn invalid_dates() {
+        assert!(!is_valid_date(2024, 0, 1));
+        assert!(!is_valid_date(2024, 13, 1));
+        assert!(!is_valid_date(2024, 1, 0));
+        assert!(!is_valid_date(2024, 4, 31));
+        assert!(is_valid_date(2024, 12, 31));
+    }
+
+    #[test]
+    fn epoch_bounds() {
+        assert_eq!(
+            to_epoch(1970, 1, 1, Duration::from_secs(0)),
+            Some(Duration::from_secs(0))
+        );
+        assert_eq!(to_epoch(1969, 12, 31, Duration::from_secs(0)), None);
+        assert_eq!(to_epoch(2024, 1, 1, Duration::from_secs(86_400)), None);
+        assert_eq!(to_epoch(2024, 2, 30, Duration::from_secs(0)), None);
+
+        let time = to_epoch(2024, 2, 29, Duration::from_millis(86_399_999)).unwrap();
+        assert_eq!(
+            from_epoch(time),
+            (2024, 2, 29, Duration::from_millis(86_399_999))
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Types for the CAN bus frames NMEA 2000 is carried in.
+
+use std::fmt;
+
+use decode::{self, DecodeError, Message};
+
+/// Address used to send a message to every device on the bus.
+pub const GLOBAL_ADDRESS: u8 = 0xff;
+
+/// A 29-bit extended CAN identifier, laid out as defined by ISO 11783 and SAE J1939.
+///
+/// ```text
+///  28    26  25  24  23          16  15          8  7           0
+/// +--------+---+----+--------------+--------------+--------------+
+/// |priority|EDP| DP | PDU format   | PDU specific | source       |
+/// +--------+---+----+--------------+--------------+--------------+
+/// ```
+///
+/// When the PDU format is below 240 the PGN is PDU1 format, which is addressed, and the PDU
+/// specific byte holds the destination address. Otherwise it is PDU2 format, which is always
+/// broadcast, and the PDU specific byte is part of the PGN.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::can::CanId;
+///
+/// // ISO Request from address 0x01 to address 0x23.
+/// let id = CanId::new(6, 59904, 0x01, 0x23);
+///
+/// assert_eq!(id.raw(), 0x18ea2301);
+/// assert_eq!(id.pgn(), 59904);
+/// assert_eq!(id.destination(), 0x23);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CanId(u32);
+
+impl CanId {
+    /// Builds an identifier from its parts. For PDU1 format PGNs the low byte of `pgn` is
+    /// replaced by `destination`. For PDU2 format PGNs `destination` is ignored.
+    pub fn new(priority: u8, pgn: u32, source: u8, destination: u8) -> CanId {
+        let pgn = pgn & 0x3ffff;
+        let pgn = if is_pdu1(pgn) {
+            (pgn & 0x3ff00) | u32::from(destination)
+        } else {
+            pgn
+        };
+
+        CanId((u32::from(priority & 0x07) << 26) | (pgn << 8) | u32::from(source))
+    }
+
+    /// Wraps a raw identifier. Anything above the low 29 bits is discarded.
+    pub fn from_raw(raw: u32) -> CanId {
+        CanId(raw & 0x1fff_ffff)
+    }
+
+    /// The raw 29-bit identifier.
+    pub fn raw(self) -> u32 {
+        self.0
+    }
+
+    /// Priority of the message, 0 being the highest and 7 the lowest.
+    pub fn priority(self) -> u8 {
+        ((self.0 >> 26) & 0x07) as u8
+    }
+
+    /// Extended data page bit. Always 0 for NMEA 2000.
+    pub fn extended_data_page(self) -> u8 {
+        ((self.0 >> 25) & 0x01) as u8
+    }
+
+    /// Data page bit.
+    pub fn data_page(self) -> u8 {
+        ((self.0 >> 24) & 0x01) as u8
+    }
+
+    /// PDU format, which determines whether the PGN is addressed or broadcast.
+    pub fn pdu_format(self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// PDU specific. Either the destination address or the group extension depending on the PDU
+    /// format.
+    pub fn pdu_specific(self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Address of the device which sent the message.
+    pub fn source(self) -> u8 {
+        self.0 as u8
+    }
+
+    /// Whether the identifier carries a PDU1 format (addressed) PGN.
+    pub fn is_pdu1(self) -> bool {
+        self.pdu_format() < 240
+    }
+
+    /// Address of the device the message is for. PDU2 format messages are always sent to the
+    /// global address.
+    pub fn destination(self) -> u8 {
+        if self.is_pdu1() {
+            self.pdu_specific()
+        } else {
+            GLOBAL_ADDRESS
+        }
+    }
+
+    /// The PGN, with the destination address removed for PDU1 format PGNs.
+    pub fn pgn(self) -> u32 {
+        let pgn = (self.0 >> 8) & 0x3ffff;
+        if self.is_pdu1() {
+            pgn & 0x3ff00
+        } else {
+            pgn
+        }
+    }
+}
+
+impl From<u32> for CanId {
+    fn from(raw: u32) -> CanId {
+        CanId::from_raw(raw)
+    }
+}
+
+impl From<CanId> for u32 {
+    fn from(id: CanId) -> u32 {
+        id.raw()
+    }
+}
+
+impl fmt::Display for CanId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:08X}", self.0)
+    }
+}
+
+/// Whether a PGN is PDU1 format, meaning the low byte of its identifier is a destination address.
+/// ISO Acknowledgement (59392) and ISO Request (59904) are both PDU1 format.
+pub fn is_pdu1(pgn: u32) -> bool {
+    (pgn >> 8) & 0xff < 240
+}
+
+/// A single CAN frame with an extended identifier and up to 8 bytes of data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Frame {
+    /// Identifier the frame was sent with.
+    pub id: CanId,
+    len: u8,
+    data: [u8; 8],
+}
+
+impl Frame {
+    /// Creates a frame, or returns `None` if `data` is longer than 8 bytes.
+    pub fn new(id: CanId, data: &[u8]) -> Option<Frame> {
+        if data.len() > 8 {
+            return None;
+        }
+
+        let mut frame = Frame {
+            id,
+            len: data.len() as u8,
+            data: [0; 8],
+        };
+        frame.data[..data.len()].copy_from_slice(data);
+        Some(frame)
+    }
+
+    /// The data bytes of the frame.
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
+}
+
+/// A complete NMEA 2000 message, either taken from a single frame or reassembled from several.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawMessage {
+    /// Identifier the message was sent with. For reassembled messages this is the identifier of
+    /// the first frame.
+    pub id: CanId,
+    /// The complete payload.
+    pub data: Vec<u8>,
+}
+
+impl From<Frame> for RawMessage {
+    fn from(frame: Frame) -> RawMessage {
+        RawMessage {
+            id: frame.id,
+            data: frame.data().to_vec(),
+        }
+    }
+}
+
+impl RawMessage {
+    /// Decodes the message using its PGN definition. See [decode](../decode/fn.decode.html).
+    pub fn decode(&self) -> Result<Message, DecodeError> {
+        decode::decode(self.id, &self.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_longer_than_eight_bytes() {
+        let id = CanId::new(2, 127250, 0x01, GLOBAL_ADDRESS);
+
+        assert!(Frame::new(id, &[0; 9]).is_none());
+        assert_eq!(Frame::new(id, &[0; 8]).unwrap().data().len(), 8);
+        assert_eq!(Frame::new(id, &[]).unwrap().data(), &[] as &[u8]);
+    }
+
+    #[test]
+    fn raw_identifier_above_29_bits() {
+        let id = CanId::from_raw(0xe9f1_1201);
+
+        assert_eq!(id.raw(), 0x09f1_1201);
+        assert_eq!(id.priority(), 2);
+        assert_eq!(id.pgn(), 127250);
+        assert_eq!(id.source(), 0x01);
+    }
+
+    #[test]
+    fn pdu1_destination() {
+        let id = CanId::new(3, 126208, 0x01, 0x23);
+
+        assert!(id.is_pdu1());
+        assert_eq!(id.pgn(), 126208);
+        assert_eq!(id.destination(), 0x23);
+        assert_eq!(CanId::from_raw(id.raw()), id);
+    }
+
+    #[test]
+    fn pdu2_ignores_destination() {
+        let id = CanId::new(2, 127250, 0x01, 0x23);
+
+        assert!(!id.is_pdu1());
+        assert_eq!(id.pgn(), 127250);
+        assert_eq!(id.destination(), GLOBAL_ADDRESS);
+        assert_eq!(id, CanId::new(2, 127250, 0x01, GLOBAL_ADDRESS));
+    }
+}
@@ -0,0 +1,263 @@
+//! CANboat's plain log format.
+//!
+//! Each line holds one complete NMEA 2000 message, as comma separated values:
+//!
+//! ```text
+//! 2011-11-24-22:42:04.388,2,127251,36,255,8,7d,0b,7d,02,00,ff,ff,ff
+//! ```
+//!
+//! That is the UTC time the message was received, then its priority, PGN, source address,
+//! destination address and length in decimal, then its data as hex bytes.
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use calendar;
+use can::{CanId, RawMessage};
+use decode::Message;
+use encode::{encode_message, EncodeError};
+
+/// Errors which may occur while parsing a line of a CANboat log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CanboatError {
+    /// The timestamp is not a valid date and time.
+    Timestamp,
+    /// The length does not match the number of data bytes.
+    Length,
+    /// The line is not laid out as the format requires.
+    Syntax,
+}
+
+impl fmt::Display for CanboatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CanboatError::Timestamp => write!(f, "invalid timestamp"),
+            CanboatError::Length => write!(f, "length does not match data"),
+            CanboatError::Syntax => write!(f, "malformed line"),
+        }
+    }
+}
+
+impl Error for CanboatError {}
+
+/// A line of a CANboat log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Time the message was received, since the Unix epoch.
+    pub timestamp: Duration,
+    /// The message.
+    pub message: RawMessage,
+}
+
+impl Record {
+    /// Parses a line. Both the `2011-11-24-22:42:04.388` form of timestamp and the ISO 8601
+    /// `2011-11-24T22:42:04.388Z` form are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::canboat::Record;
+    ///
+    /// let line = "2011-11-24-22:42:04.388,2,127251,36,255,8,7d,0b,7d,02,00,ff,ff,ff";
+    /// let record = Record::parse(line).unwrap();
+    ///
+    /// assert_eq!(record.message.id.pgn(), 127251);
+    /// assert_eq!(record.message.id.source(), 36);
+    /// assert_eq!(record.to_string(), line);
+    /// assert!(Record::parse("2011-11-24é22:42:04.388,2,127251,36,255,1,7d").is_err());
+    /// assert!(Record::parse("2011-02-31-22:42:04.388,2,127251,36,255,1,7d").is_err());
+    /// ```
+    pub fn parse(line: &str) -> Result<Record, CanboatError> {
+        let mut parts = line.trim().split(',');
+
+        let timestamp = parse_timestamp(parts.next().ok_or(CanboatError::Syntax)?)
+            .ok_or(CanboatError::Timestamp)?;
+        let mut number = || -> Result<u32, CanboatError> {
+            parts
+                .next()
+                .and_then(|p| p.trim().parse().ok())
+                .ok_or(CanboatError::Syntax)
+        };
+        let priority = number()?;
+        let pgn = number()?;
+        let source = number()?;
+        let destination = number()?;
+        let length = number()?;
+        if priority > 7 || source > 255 || destination > 255 {
+            return Err(CanboatError::Syntax);
+        }
+
+        let data = parts
+            .map(|p| u8::from_str_radix(p.trim(), 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| CanboatError::Syntax)?;
+        if data.len() != length as usize {
+            return Err(CanboatError::Length);
+        }
+
+        Ok(Record {
+            timestamp,
+            message: RawMessage {
+                id: CanId::new(priority as u8, pgn, source as u8, destination as u8),
+                data,
+            },
+        })
+    }
+
+    /// Creates a record of a decoded message, encoding it back into its payload.
+    pub fn from_message(timestamp: Duration, message: &Message) -> Result<Record, EncodeError> {
+        Ok(Record {
+            timestamp,
+            message: encode_message(message)?,
+        })
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day, time) = calendar::from_epoch(self.timestamp);
+        let seconds = time.as_secs();
+        let id = self.message.id;
+
+        write!(
+            f,
+            "{:04}-{:02}-{:02}-{:02}:{:02}:{:02}.{:03},{},{},{},{},{}",
+            year,
+            month,
+            day,
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            time.subsec_millis(),
+            id.priority(),
+            id.pgn(),
+            id.source(),
+            id.destination(),
+            self.message.data.len()
+        )?;
+        for byte in &self.message.data {
+            write!(f, ",{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses `YYYY-MM-DD-HH:MM:SS.sss`, with `T` or a space also accepted between the date and time
+/// and an optional trailing `Z`.
+fn parse_timestamp(text: &str) -> Option<Duration> {
+    let text = text.trim().trim_end_matches('Z');
+    if text.len() < 19 || !text.is_ascii() {
+        return None;
+    }
+
+    let (date, time) = (&text[..10], &text[11..]);
+    if !matches!(&text[10..11], "-" | "T" | " ") {
+        return None;
+    }
+
+    let mut date = date.split('-');
+    let year: i64 = date.next()?.parse().ok()?;
+    let month: u32 = date.next()?.parse().ok()?;
+    let day: u32 = date.next()?.parse().ok()?;
+
+    let mut time = time.split(':');
+    let hours: u64 = time.next()?.parse().ok()?;
+    let minutes: u64 = time.next()?.parse().ok()?;
+    let seconds: f64 = time.next()?.parse().ok()?;
+    if time.next().is_some() || hours > 23 || minutes > 59 || !(0.0..61.0).contains(&seconds) {
+        return None;
+    }
+
+    let millis = (seconds * 1000.0).round() as u64;
+    let time_of_day = Duration::from_millis((hours * 60 + minutes) * 60_000 + millis);
+    calendar::to_epoch(year, month, day, time_of_day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINE: &str = "2011-11-24-22:42:04.388,2,127251,36,255,8,7d,0b,7d,02,00,ff,ff,ff";
+
+    #[test]
+    fn timestamp_forms() {
+        let record = Record::parse(LINE).unwrap();
+
+        for line in &[
+            "2011-11-24T22:42:04.388Z,2,127251,36,255,8,7d,0b,7d,02,00,ff,ff,ff",
+            "2011-11-24 22:42:04.388,2,127251,36,255,8,7d,0b,7d,02,00,ff,ff,ff",
+            "2011-11-24-22:42:4.388,2,127251,36,255,8,7d,0b,7d,02,00,ff,ff,ff\r\n",
+        ] {
+            assert_eq!(Record::parse(line), Ok(record.clone()), "{}", line);
+        }
+        assert_eq!(record.timestamp, Duration::from_millis(1_322_174_524_388));
+    }
+
+    #[test]
+    fn addressed_message() {
+        let record = Record::parse("2011-11-24-22:42:04.388,3,59904,1,35,3,00,ee,00").unwrap();
+
+        assert_eq!(record.message.id.pgn(), 59904);
+        assert_eq!(record.message.id.destination(), 35);
+        assert_eq!(
+            record.to_string(),
+            "2011-11-24-22:42:04.388,3,59904,1,35,3,00,ee,00"
+        );
+    }
+
+    #[test]
+    fn rejected_timestamps() {
+        for timestamp in &[
+            "",
+            "2011-11-24",
+            "2011-11-24/22:42:04.388",
+            "2011-13-24-22:42:04.388",
+            "2011-11-31-22:42:04.388",
+            "2011-11-24-24:42:04.388",
+            "2011-11-24-22:60:04.388",
+            "2011-11-24-22:42:61.000",
+            "2011-11-24-22:42:NaN",
+            "2011-11-24-22:42:04:00",
+            "1969-12-31-23:59:59.000",
+            "2011-11-24-22:42",
+        ] {
+            let line = format!("{},2,127251,36,255,1,7d", timestamp);
+            assert_eq!(
+                Record::parse(&line),
+                Err(CanboatError::Timestamp),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn rejected_lines() {
+        for line in &[
+            "2011-11-24-22:42:04.388",
+            "2011-11-24-22:42:04.388,2,127251,36,255",
+            "2011-11-24-22:42:04.388,8,127251,36,255,1,7d",
+            "2011-11-24-22:42:04.388,2,127251,256,255,1,7d",
+            "2011-11-24-22:42:04.388,2,127251,36,256,1,7d",
+            "2011-11-24-22:42:04.388,2,127251,-1,255,1,7d",
+            "2011-11-24-22:42:04.388,2,127251,36,255,1,7g",
+            "2011-11-24-22:42:04.388,2,127251,36,255,1,17d",
+            "2011-11-24-22:42:04.388,2,127251,36,255,1,",
+        ] {
+            assert_eq!(Record::parse(line), Err(CanboatError::Syntax), "{}", line);
+        }
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let line = "2011-11-24-22:42:04.388,2,127251,36,255,2,7d";
+
+        assert_eq!(Record::parse(line), Err(CanboatError::Length));
+        assert_eq!(
+            Record::parse("2011-11-24-22:42:04.388,2,127251,36,255,1"),
+            Err(CanboatError::Length)
+        );
+    }
+}
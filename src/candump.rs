@@ -0,0 +1,188 @@
+//! Log files written by `candump -L` from Linux can-utils.
+//!
+//! Each line holds one CAN frame:
+//!
+//! ```text
+//! (1436509052.249713) can0 19F51323#0102030405060708
+//! ```
+//!
+//! That is the time the frame was received in seconds since the Unix epoch, the interface it was
+//! received on, and the identifier and data in hex. NMEA 2000 only uses extended frames, which
+//! have 8 digit identifiers.
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use can::{CanId, Frame};
+use hex;
+
+/// Errors which may occur while parsing a line of a candump log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CandumpError {
+    /// The frame is a standard, remote or CAN FD frame, none of which are used by NMEA 2000.
+    Unsupported,
+    /// The line is not laid out as the format requires.
+    Syntax,
+}
+
+impl fmt::Display for CandumpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CandumpError::Unsupported => write!(f, "not an extended data frame"),
+            CandumpError::Syntax => write!(f, "malformed line"),
+        }
+    }
+}
+
+impl Error for CandumpError {}
+
+/// A line of a candump log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Time the frame was received, since the Unix epoch.
+    pub timestamp: Duration,
+    /// Name of the interface the frame was received on.
+    pub interface: String,
+    /// The frame.
+    pub frame: Frame,
+}
+
+impl Record {
+    /// Parses a line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::candump::Record;
+    ///
+    /// let line = "(1436509052.249713) can0 19F51323#0102030405060708";
+    /// let record = Record::parse(line).unwrap();
+    ///
+    /// assert_eq!(record.interface, "can0");
+    /// assert_eq!(record.frame.id.pgn(), 128275);
+    /// assert_eq!(record.to_string(), line);
+    /// ```
+    pub fn parse(line: &str) -> Result<Record, CandumpError> {
+        let mut parts = line.split_whitespace();
+        let time = parts.next().ok_or(CandumpError::Syntax)?;
+        let interface = parts.next().ok_or(CandumpError::Syntax)?;
+        let frame = parts.next().ok_or(CandumpError::Syntax)?;
+        // Anything after the frame is a comment.
+
+        if !time.starts_with('(') || !time.ends_with(')') {
+            return Err(CandumpError::Syntax);
+        }
+        let timestamp = parse_time(&time[1..time.len() - 1]).ok_or(CandumpError::Syntax)?;
+
+        let hash = frame.find('#').ok_or(CandumpError::Syntax)?;
+        let (id, data) = (&frame[..hash], &frame[hash + 1..]);
+        if id.len() != 8 || data.starts_with('#') || data.starts_with('R') {
+            return Err(CandumpError::Unsupported);
+        }
+
+        let id = u32::from_str_radix(id, 16).map_err(|_| CandumpError::Syntax)?;
+        let data = hex::decode(data).ok_or(CandumpError::Syntax)?;
+        let frame = Frame::new(CanId::from_raw(id), &data).ok_or(CandumpError::Syntax)?;
+
+        Ok(Record {
+            timestamp,
+            interface: interface.to_string(),
+            frame,
+        })
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "({}.{:06}) {} {}#{}",
+            self.timestamp.as_secs(),
+            self.timestamp.subsec_micros(),
+            self.interface,
+            self.frame.id,
+            hex::encode(self.frame.data(), "")
+        )
+    }
+}
+
+/// Parses seconds with an optional fraction.
+fn parse_time(text: &str) -> Option<Duration> {
+    let (seconds, fraction) = match text.find('.') {
+        Some(dot) => (&text[..dot], &text[dot + 1..]),
+        None => (text, ""),
+    };
+    let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if fraction.len() > 9 || !digits(seconds) || !digits(fraction) {
+        return None;
+    }
+
+    let seconds: u64 = seconds.parse().ok()?;
+    let nanos: u32 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<9}", fraction).parse().ok()?
+    };
+    Some(Duration::new(seconds, nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_precision() {
+        let record = Record::parse("(1436509052) can0 19F51323#").unwrap();
+        assert_eq!(record.timestamp, Duration::from_secs(1_436_509_052));
+        assert!(record.frame.data().is_empty());
+
+        let record = Record::parse("(1436509052.123456789) vcan0 19F51323#01").unwrap();
+        assert_eq!(record.timestamp, Duration::new(1_436_509_052, 123_456_789));
+    }
+
+    #[test]
+    fn trailing_comment() {
+        let record = Record::parse("(1436509052.249713) can0 19F51323#01 T").unwrap();
+
+        assert_eq!(record.frame.data(), &[0x01]);
+    }
+
+    #[test]
+    fn unsupported_frames() {
+        for line in &[
+            "(1436509052.249713) can0 123#0102",
+            "(1436509052.249713) can0 19F51323##10102",
+            "(1436509052.249713) can0 19F51323#R",
+        ] {
+            assert_eq!(
+                Record::parse(line),
+                Err(CandumpError::Unsupported),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn rejected_lines() {
+        for line in &[
+            "",
+            "(1436509052.249713) can0",
+            "1436509052.249713 can0 19F51323#01",
+            "(1436509052.249713 can0 19F51323#01",
+            "() can0 19F51323#01",
+            "(.249713) can0 19F51323#01",
+            "(+1436509052.249713) can0 19F51323#01",
+            "(1436509052.2497a3) can0 19F51323#01",
+            "(1436509052.1234567890) can0 19F51323#01",
+            "(1436509052.249713) can0 19F51323",
+            "(1436509052.249713) can0 19F5132G#01",
+            "(1436509052.249713) can0 19F51323#010",
+            "(1436509052.249713) can0 19F51323#0G",
+            "(1436509052.249713) can0 19F51323#010203040506070809",
+        ] {
+            assert_eq!(Record::parse(line), Err(CandumpError::Syntax), "{}", line);
+        }
+    }
+}
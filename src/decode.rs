@@ -0,0 +1,587 @@
+//! Decoding of NMEA 2000 payloads into named, typed values.
+//!
+//! A payload is decoded by looking up its [Pgn](../struct.Pgn.html) and walking the `fields` of
+//! the definition, extracting `size` bits at `start` from the payload and scaling the raw value by
+//! the field's `offset` and `multiplier`.
+
+use std::error::Error;
+use std::fmt;
+
+use bits::{mask, BitReader};
+use can::CanId;
+use registry;
+use {Field, FieldType, Pgn, Unit};
+
+/// A value decoded from a single field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// An integer value which has no multiplier applied to it.
+    Integer(i64),
+    /// An integer value which has been scaled by the field's multiplier, or a floating point
+    /// value read directly from the wire.
+    Decimal(f64),
+    /// Text decoded from one of the string field types.
+    String(String),
+    /// Raw bytes from a variable length field.
+    Bytes(Vec<u8>),
+    /// The raw value of a lookup field and its name, if the field's lookup table has one for it.
+    Lookup(u64, Option<&'static str>),
+    /// The sender has no data for the field. Sent as the largest value the field can hold.
+    NotAvailable,
+    /// The sender has data for the field but it is out of the range the field can represent, or
+    /// the sensor is faulty. Sent as one less than the largest value.
+    OutOfRange,
+    /// Sent as two less than the largest value, which is reserved for future use.
+    Reserved,
+}
+
+/// A decoded field, pairing the value with the name and unit from its definition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldValue {
+    /// Name of the field, as given in the [Field](../struct.Field.html) definition.
+    pub name: &'static str,
+    /// Unit of measure of `value`, if any.
+    pub unit: Option<Unit>,
+    /// The decoded value.
+    pub value: Value,
+}
+
+/// A decoded NMEA 2000 message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    /// Name of the PGN the message was decoded with.
+    pub name: &'static str,
+    /// Integer ID of the PGN.
+    pub pgn: u32,
+    /// Priority from the CAN identifier, 0 being the highest priority.
+    pub priority: u8,
+    /// Address of the device which sent the message.
+    pub source: u8,
+    /// Address of the device the message was sent to, 255 being the global address.
+    pub destination: u8,
+    /// Decoded fields in the order they appear in the payload.
+    pub fields: Vec<FieldValue>,
+}
+
+impl Message {
+    /// Returns the value of the first field with the given name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|f| f.name == name)
+            .map(|f| &f.value)
+    }
+}
+
+/// Errors which may occur while decoding a payload.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    /// There is no definition for the PGN which applies to the payload.
+    UnknownPgn(u32),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::UnknownPgn(pgn) => write!(f, "no definition for PGN {}", pgn),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Decodes a payload received with the given 29-bit CAN identifier.
+///
+/// The priority, PGN, source and destination are taken from the identifier (see
+/// [CanId](../can/struct.CanId.html)), and the PGN and payload are used to find the definition the
+/// payload is decoded with in the [registry](../registry/index.html).
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::*;
+///
+/// // ISO Request for PGN 60928 (ISO Address Claim) from address 1 to the global address.
+/// let message = decode(0x18eaff01, &[0x00, 0xee, 0x00]).unwrap();
+///
+/// assert_eq!(message.pgn, 59904);
+/// assert_eq!(message.get("PGN"), Some(&Value::Integer(60928)));
+/// ```
+pub fn decode<I: Into<CanId>>(id: I, data: &[u8]) -> Result<Message, DecodeError> {
+    let id = id.into();
+    let pgn = id.pgn();
+
+    let definition = registry::resolve(pgn, data).ok_or(DecodeError::UnknownPgn(pgn))?;
+
+    Ok(Message {
+        name: definition.name,
+        pgn,
+        priority: id.priority(),
+        source: id.source(),
+        destination: id.destination(),
+        fields: decode_fields(definition, data),
+    })
+}
+
+/// Decodes a payload using the given PGN definition.
+///
+/// Fields which lie beyond the end of the payload are left out of the result. If the definition
+/// has repeating fields, they are decoded for as many repetitions as the payload holds.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::decode::decode_fields;
+/// use libnmea::{registry, Value};
+///
+/// let battery = &registry::get(127508)[0];
+/// let value = |data: &[u8], name: &str| {
+///     decode_fields(battery, data).into_iter().find(|f| f.name == name).unwrap().value
+/// };
+///
+/// // A disconnected voltage sensor, rather than 655.35V.
+/// let data = [0x00, 0xff, 0xff, 0xfe, 0x7f, 0xff, 0xff, 0x01];
+/// assert_eq!(value(&data, "Voltage"), Value::NotAvailable);
+/// assert_eq!(value(&data, "Current"), Value::OutOfRange);
+///
+/// let data = [0x00, 0xfe, 0xff, 0xfd, 0x7f, 0xff, 0xff, 0x01];
+/// assert_eq!(value(&data, "Voltage"), Value::OutOfRange);
+/// assert_eq!(value(&data, "Current"), Value::Reserved);
+///
+/// let data = [0x00, 0xb0, 0x04, 0xff, 0x7f, 0xff, 0xff, 0x01];
+/// assert_eq!(value(&data, "Voltage"), Value::Decimal(12.0));
+/// assert_eq!(value(&data, "Current"), Value::NotAvailable);
+/// ```
+pub fn decode_fields(pgn: &Pgn, data: &[u8]) -> Vec<FieldValue> {
+    let repeating = pgn.repeating_fields as usize;
+    let split = pgn.fields.len().saturating_sub(repeating);
+    let (fixed, repeated) = pgn.fields.split_at(split);
+
+    let data = BitReader::new(data);
+    let mut values = Vec::new();
+    // Variable length strings move every field after them by however much longer or shorter
+    // they are than their nominal size.
+    let mut shift: isize = 0;
+
+    for field in fixed {
+        if let Some(value) = decode_field(field, &data, &mut shift, 0) {
+            values.push(value);
+        }
+    }
+
+    if let (Some(first), Some(last)) = (repeated.first(), repeated.last()) {
+        let span = (last.start + last.size - first.start) as usize;
+        let mut base = 0;
+        while span > 0 && field_position(first, shift, base) < data.bit_len() {
+            for field in repeated {
+                if let Some(value) = decode_field(field, &data, &mut shift, base) {
+                    values.push(value);
+                }
+            }
+            base += span;
+        }
+    }
+
+    values
+}
+
+fn field_position(field: &Field, shift: isize, base: usize) -> usize {
+    (field.start as isize + shift) as usize + base
+}
+
+fn decode_field(
+    field: &Field,
+    data: &BitReader,
+    shift: &mut isize,
+    base: usize,
+) -> Option<FieldValue> {
+    let start = field_position(field, *shift, base);
+    let size = field.size as usize;
+
+    let value = match field.field_type {
+        Some(FieldType::NotUsed) => return None,
+        Some(FieldType::Float) => {
+            let raw = data.read_unsigned(start, size)?;
+            match size {
+                32 => Value::Decimal(f64::from(f32::from_bits(raw as u32))),
+                64 => Value::Decimal(f64::from_bits(raw)),
+                _ => return None,
+            }
+        }
+        Some(FieldType::AsciiString) | Some(FieldType::FixedString) => {
+            Value::String(ascii(data.read_bytes(start, size)?))
+        }
+        Some(FieldType::WideString) => Value::String(utf16(data.read_bytes(start, size)?)),
+        Some(FieldType::PascalString) => {
+            // The length byte counts itself and the control byte which follows it. A control
+            // byte of 0 means the text is UTF-16, 1 means it is ASCII.
+            let header = data.read_bytes(start, 16)?;
+            let length = (header[0] as usize).max(2);
+            let text = data.read_bytes(start + 16, (length - 2) * 8)?;
+            *shift += (length * 8) as isize - size as isize;
+            if header[1] == 0 {
+                Value::String(utf16(text))
+            } else {
+                Value::String(ascii(text))
+            }
+        }
+        Some(FieldType::Variable) => {
+            let size = if size == 0 {
+                data.bit_len().checked_sub(start)?
+            } else {
+                size
+            };
+            Value::Bytes(data.read_bytes(start, size)?.to_vec())
+        }
+        Some(FieldType::Lookup) => {
+            let raw = data.read_unsigned(start, size)?;
+            let name = field.lookup.and_then(|l| l.name_of(raw as u32));
+            match sentinel(field, raw) {
+                Some(value) if name.is_none() => value,
+                _ => Value::Lookup(raw, name),
+            }
+        }
+        _ => {
+            let raw = data.read_unsigned(start, size)?;
+            match sentinel(field, raw) {
+                Some(value) => value,
+                None if field.signed => scale(field, data.read_signed(start, size)?),
+                None => scale(field, raw as i64),
+            }
+        }
+    };
+
+    Some(FieldValue {
+        name: field.name,
+        unit: field.unit,
+        value,
+    })
+}
+
+fn scale(field: &Field, raw: i64) -> Value {
+    if field.multiplier != 0.0 {
+        Value::Decimal((raw + field.offset) as f64 * field.multiplier)
+    } else if field.field_type == Some(FieldType::Decimal) {
+        Value::Decimal((raw + field.offset) as f64)
+    } else {
+        Value::Integer(raw + field.offset)
+    }
+}
+
+/// Checks a raw value against the values NMEA 2000 reserves at the top of a field's range. The
+/// largest value means the data is not available, the one below it that the data is out of
+/// range, and the one below that is reserved. Fields shorter than 4 bits and lookups only reserve
+/// the largest value, and single bit fields reserve nothing. Lookups which name their largest value
+/// do not reserve it.
+fn sentinel(field: &Field, raw: u64) -> Option<Value> {
+    let size = field.size as usize;
+    if size < 2 {
+        return None;
+    }
+
+    let max = if field.signed {
+        mask(size - 1)
+    } else {
+        mask(size)
+    };
+
+    if raw == max {
+        Some(Value::NotAvailable)
+    } else if size < 4 || field.field_type == Some(FieldType::Lookup) {
+        None
+    } else if raw == max - 1 {
+        Some(Value::OutOfRange)
+    } else if raw == max - 2 {
+        Some(Value::Reserved)
+    } else {
+        None
+    }
+}
+
+/// Strings are padded with NUL, 0xff, spaces or '@' depending on the sender.
+fn ascii(data: &[u8]) -> String {
+    let end = data
+        .iter()
+        .position(|&b| b == 0x00 || b == 0xff)
+        .unwrap_or(data.len());
+
+    data[..end]
+        .iter()
+        .map(|&b| b as char)
+        .collect::<String>()
+        .trim_end_matches([' ', '@'])
+        .to_string()
+}
+
+fn utf16(data: &[u8]) -> String {
+    let units: Vec<u16> = data
+        .chunks(2)
+        .filter(|c| c.len() == 2)
+        .map(|c| u16::from(c[0]) | u16::from(c[1]) << 8)
+        .take_while(|&u| u != 0x0000 && u != 0xffff)
+        .collect();
+
+    String::from_utf16_lossy(&units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bits::BitWriter;
+    use lookup::Lookup;
+    use PgnCategory;
+
+    static STATES: Lookup = Lookup {
+        name: "States",
+        values: &[(0, "Off"), (1, "On"), (3, "Unavailable")],
+    };
+
+    fn pgn(fields: Vec<Field>) -> Pgn {
+        Pgn {
+            name: "Test",
+            category: PgnCategory::General,
+            pgn: 65280,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields,
+        }
+    }
+
+    fn integer(size: u16, signed: bool) -> Field {
+        Field {
+            name: "Value",
+            field_type: Some(FieldType::Integer),
+            size,
+            signed,
+            ..Default::default()
+        }
+    }
+
+    fn lookup(size: u16) -> Field {
+        Field {
+            name: "Value",
+            field_type: Some(FieldType::Lookup),
+            size,
+            lookup: Some(&STATES),
+            ..Default::default()
+        }
+    }
+
+    /// Decodes `raw` written at the start of an otherwise unset payload.
+    fn value(field: Field, raw: u64) -> Value {
+        let mut writer = BitWriter::new(8);
+        writer.write_unsigned(0, field.size as usize, raw);
+        let data = writer.into_bytes();
+
+        decode_fields(&pgn(vec![field]), &data).remove(0).value
+    }
+
+    #[test]
+    fn single_bit_reserves_nothing() {
+        assert_eq!(value(integer(1, false), 0), Value::Integer(0));
+        assert_eq!(value(integer(1, false), 1), Value::Integer(1));
+    }
+
+    #[test]
+    fn two_and_three_bits_reserve_only_max() {
+        assert_eq!(value(integer(2, false), 3), Value::NotAvailable);
+        assert_eq!(value(integer(2, false), 2), Value::Integer(2));
+        assert_eq!(value(integer(3, false), 7), Value::NotAvailable);
+        assert_eq!(value(integer(3, false), 6), Value::Integer(6));
+        assert_eq!(value(integer(3, false), 5), Value::Integer(5));
+    }
+
+    #[test]
+    fn four_bits_and_up_reserve_three_values() {
+        for &size in &[4u16, 8, 16, 32, 64] {
+            let max = mask(size as usize);
+            assert_eq!(value(integer(size, false), max), Value::NotAvailable);
+            assert_eq!(value(integer(size, false), max - 1), Value::OutOfRange);
+            assert_eq!(value(integer(size, false), max - 2), Value::Reserved);
+            assert_eq!(
+                value(integer(size, false), max - 3),
+                Value::Integer((max - 3) as i64)
+            );
+        }
+    }
+
+    #[test]
+    fn signed_fields_reserve_below_positive_max() {
+        assert_eq!(value(integer(8, true), 0x7f), Value::NotAvailable);
+        assert_eq!(value(integer(8, true), 0x7e), Value::OutOfRange);
+        assert_eq!(value(integer(8, true), 0x7d), Value::Reserved);
+        assert_eq!(value(integer(8, true), 0x7c), Value::Integer(124));
+        assert_eq!(value(integer(8, true), 0xff), Value::Integer(-1));
+        assert_eq!(value(integer(8, true), 0x80), Value::Integer(-128));
+
+        assert_eq!(value(integer(16, true), 0x7fff), Value::NotAvailable);
+        assert_eq!(value(integer(16, true), 0xffff), Value::Integer(-1));
+        assert_eq!(value(integer(2, true), 1), Value::NotAvailable);
+        assert_eq!(value(integer(2, true), 3), Value::Integer(-1));
+    }
+
+    #[test]
+    fn scaled_fields_keep_sentinels() {
+        let scaled = || Field {
+            multiplier: 0.01,
+            offset: -100,
+            ..integer(16, false)
+        };
+
+        assert_eq!(value(scaled(), 0xffff), Value::NotAvailable);
+        assert_eq!(value(scaled(), 1300), Value::Decimal(12.0));
+    }
+
+    #[test]
+    fn lookups_reserve_only_unnamed_max() {
+        assert_eq!(value(lookup(2), 3), Value::Lookup(3, Some("Unavailable")));
+        assert_eq!(value(lookup(2), 2), Value::Lookup(2, None));
+        assert_eq!(value(lookup(4), 15), Value::NotAvailable);
+        assert_eq!(value(lookup(4), 14), Value::Lookup(14, None));
+        assert_eq!(value(lookup(4), 13), Value::Lookup(13, None));
+        assert_eq!(value(lookup(4), 1), Value::Lookup(1, Some("On")));
+    }
+
+    fn field(name: &'static str, field_type: FieldType, start: u16, size: u16) -> Field {
+        Field {
+            name,
+            field_type: Some(field_type),
+            start,
+            size,
+            ..Default::default()
+        }
+    }
+
+    fn values(pgn: &Pgn, data: &[u8]) -> Vec<(&'static str, Value)> {
+        decode_fields(pgn, data)
+            .into_iter()
+            .map(|f| (f.name, f.value))
+            .collect()
+    }
+
+    #[test]
+    fn unknown_pgn() {
+        let id = CanId::new(2, 130000, 0x01, 0xff);
+
+        assert_eq!(decode(id, &[0; 8]), Err(DecodeError::UnknownPgn(130000)));
+    }
+
+    #[test]
+    fn identifier_parts() {
+        let message = decode(CanId::new(3, 59904, 0x01, 0x23), &[0x00, 0xee, 0x00]).unwrap();
+
+        assert_eq!(message.priority, 3);
+        assert_eq!(message.source, 0x01);
+        assert_eq!(message.destination, 0x23);
+    }
+
+    #[test]
+    fn fields_past_the_end_are_left_out() {
+        let pgn = pgn(vec![
+            field("A", FieldType::Integer, 0, 8),
+            field("B", FieldType::Integer, 8, 16),
+        ]);
+
+        assert_eq!(values(&pgn, &[1, 2]), vec![("A", Value::Integer(1))]);
+        assert_eq!(values(&pgn, &[]), vec![]);
+    }
+
+    #[test]
+    fn repeating_fields() {
+        let mut pgn = pgn(vec![
+            field("Count", FieldType::Integer, 0, 8),
+            field("Id", FieldType::Integer, 8, 8),
+            field("Level", FieldType::Integer, 16, 8),
+        ]);
+        pgn.repeating_fields = 2;
+
+        assert_eq!(
+            values(&pgn, &[2, 1, 10, 2, 20]),
+            vec![
+                ("Count", Value::Integer(2)),
+                ("Id", Value::Integer(1)),
+                ("Level", Value::Integer(10)),
+                ("Id", Value::Integer(2)),
+                ("Level", Value::Integer(20)),
+            ]
+        );
+        assert_eq!(values(&pgn, &[0]), vec![("Count", Value::Integer(0))]);
+    }
+
+    #[test]
+    fn pascal_string_moves_later_fields() {
+        let pgn = pgn(vec![
+            field("Name", FieldType::PascalString, 0, 16),
+            field("After", FieldType::Integer, 16, 8),
+        ]);
+
+        assert_eq!(
+            values(&pgn, &[5, 1, b'a', b'b', b'c', 42]),
+            vec![
+                ("Name", Value::String("abc".to_string())),
+                ("After", Value::Integer(42)),
+            ]
+        );
+        assert_eq!(
+            values(&pgn, &[6, 0, b'a', 0, b'b', 0, 42]),
+            vec![
+                ("Name", Value::String("ab".to_string())),
+                ("After", Value::Integer(42)),
+            ]
+        );
+        assert_eq!(
+            values(&pgn, &[2, 1, 42]),
+            vec![
+                ("Name", Value::String(String::new())),
+                ("After", Value::Integer(42)),
+            ]
+        );
+    }
+
+    #[test]
+    fn pascal_string_longer_than_payload() {
+        let pgn = pgn(vec![field("Name", FieldType::PascalString, 0, 16)]);
+
+        assert_eq!(values(&pgn, &[10, 1, b'a']), vec![]);
+    }
+
+    #[test]
+    fn variable_field_runs_to_the_end() {
+        let pgn = pgn(vec![
+            field("A", FieldType::Integer, 0, 8),
+            field("Rest", FieldType::Variable, 8, 0),
+        ]);
+
+        assert_eq!(
+            values(&pgn, &[1, 2, 3]),
+            vec![("A", Value::Integer(1)), ("Rest", Value::Bytes(vec![2, 3]))]
+        );
+        assert_eq!(
+            values(&pgn, &[1]),
+            vec![("A", Value::Integer(1)), ("Rest", Value::Bytes(vec![]))]
+        );
+    }
+
+    #[test]
+    fn string_padding_is_trimmed() {
+        let pgn = pgn(vec![field("Name", FieldType::AsciiString, 0, 64)]);
+
+        assert_eq!(
+            values(&pgn, b"AB C @@\xff"),
+            vec![("Name", Value::String("AB C".to_string()))]
+        );
+    }
+
+    #[test]
+    fn float_fields() {
+        let pgn = pgn(vec![
+            field("Single", FieldType::Float, 0, 32),
+            field("Odd", FieldType::Float, 32, 16),
+        ]);
+        let mut data = 1.5f32.to_bits().to_le_bytes().to_vec();
+        data.extend_from_slice(&[0, 0]);
+
+        assert_eq!(values(&pgn, &data), vec![("Single", Value::Decimal(1.5))]);
+    }
+}
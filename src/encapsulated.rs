@@ -0,0 +1,373 @@
+//! NMEA 2000 messages wrapped in NMEA 0183 sentences by multiplexers.
+//!
+//! SeaSmart.net and multiplexers following it send whole NMEA 2000 messages as `$PCDIN`
+//! sentences:
+//!
+//! ```text
+//! $PCDIN,01F119,00000000,0F,2AAF00D1067414FF*59
+//! ```
+//!
+//! That is the PGN, a timestamp and the source address, then the data, all in hex.
+//!
+//! ShipModul MiniPlex multiplexers send single CAN frames as `$MXPGN` sentences:
+//!
+//! ```text
+//! $MXPGN,01F801,2801,C1308AC40C5DE343*19
+//! ```
+//!
+//! That is the PGN, an attribute word and the data in hex, with the data most significant byte
+//! first, which is the reverse of the order it has on the bus. The attribute word holds whether
+//! the frame is to be sent (bit 15), its priority (bits 12 to 14), its length (bits 8 to 11), and
+//! its source address when received or its destination address when sent (bits 0 to 7).
+
+use std::error::Error;
+use std::fmt;
+
+use can::{CanId, Frame, RawMessage, GLOBAL_ADDRESS};
+use hex;
+use nmea0183::{Nmea0183Error, Sentence, Start};
+
+/// Priority given to messages from `$PCDIN` sentences, which do not carry one.
+pub const PCDIN_PRIORITY: u8 = 7;
+
+/// Errors which may occur while parsing an encapsulated message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncapsulatedError {
+    /// The checksum of the sentence does not match its contents.
+    Checksum,
+    /// The sentence is a different kind of sentence.
+    WrongSentence,
+    /// The sentence is not laid out as the format requires.
+    Syntax,
+}
+
+impl fmt::Display for EncapsulatedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EncapsulatedError::Checksum => write!(f, "checksum mismatch"),
+            EncapsulatedError::WrongSentence => write!(f, "not the expected sentence"),
+            EncapsulatedError::Syntax => write!(f, "malformed sentence"),
+        }
+    }
+}
+
+impl Error for EncapsulatedError {}
+
+impl From<Nmea0183Error> for EncapsulatedError {
+    fn from(error: Nmea0183Error) -> EncapsulatedError {
+        match error {
+            Nmea0183Error::Checksum { .. } => EncapsulatedError::Checksum,
+            _ => EncapsulatedError::Syntax,
+        }
+    }
+}
+
+/// A complete NMEA 2000 message from a `$PCDIN` sentence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pcdin {
+    /// Timestamp from the multiplexer.
+    pub timestamp: u32,
+    /// The message. `$PCDIN` carries no priority or destination, so it is given
+    /// [PCDIN_PRIORITY](constant.PCDIN_PRIORITY.html) and the global address.
+    pub message: RawMessage,
+}
+
+impl Pcdin {
+    /// Parses a `$PCDIN` sentence, checking its checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::encapsulated::Pcdin;
+    ///
+    /// let sentence = "$PCDIN,01F119,00000000,0F,2AAF00D1067414FF*59";
+    /// let pcdin = Pcdin::parse(sentence).unwrap();
+    ///
+    /// assert_eq!(pcdin.message.id.pgn(), 127257);
+    /// assert_eq!(pcdin.message.id.source(), 0x0f);
+    /// assert_eq!(pcdin.to_string(), sentence);
+    /// ```
+    ///
+    /// The message decodes like any other:
+    ///
+    /// ```
+    /// use libnmea::encapsulated::Pcdin;
+    /// use libnmea::Value;
+    ///
+    /// let pcdin = Pcdin::parse("$PCDIN,00EA00,00000000,23,00EE00*55").unwrap();
+    /// let message = pcdin.message.decode().unwrap();
+    ///
+    /// assert_eq!(message.name, "ISO Request");
+    /// assert_eq!(message.get("PGN"), Some(&Value::Integer(60928)));
+    /// ```
+    pub fn parse(sentence: &str) -> Result<Pcdin, EncapsulatedError> {
+        let fields = fields(sentence, "PCDIN")?;
+        if fields.len() != 4 {
+            return Err(EncapsulatedError::Syntax);
+        }
+
+        let pgn = number(&fields[0], 0x3ffff)?;
+        let timestamp = number(&fields[1], u32::MAX)?;
+        let source = number(&fields[2], 0xff)? as u8;
+        let data = hex::decode(&fields[3]).ok_or(EncapsulatedError::Syntax)?;
+
+        Ok(Pcdin {
+            timestamp,
+            message: RawMessage {
+                id: CanId::new(PCDIN_PRIORITY, pgn, source, GLOBAL_ADDRESS),
+                data,
+            },
+        })
+    }
+}
+
+impl fmt::Display for Pcdin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sentence = sentence(
+            "P",
+            "CDIN",
+            vec![
+                format!("{:06X}", self.message.id.pgn()),
+                format!("{:08X}", self.timestamp),
+                format!("{:02X}", self.message.id.source()),
+                hex::encode(&self.message.data, ""),
+            ],
+        );
+        write!(f, "{}", sentence)
+    }
+}
+
+/// A single CAN frame from a `$MXPGN` sentence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mxpgn {
+    /// Whether the frame is for the multiplexer to send, rather than one it received.
+    pub transmit: bool,
+    /// The frame. Received frames have the global address as their destination, and frames to be
+    /// sent have 0 as their source, as the attribute word only has room for one address.
+    pub frame: Frame,
+}
+
+impl Mxpgn {
+    /// Parses a `$MXPGN` sentence, checking its checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::encapsulated::Mxpgn;
+    ///
+    /// let sentence = "$MXPGN,01F801,2801,C1308AC40C5DE343*19";
+    /// let mxpgn = Mxpgn::parse(sentence).unwrap();
+    ///
+    /// assert_eq!(mxpgn.frame.id.pgn(), 129025);
+    /// assert_eq!(mxpgn.frame.id.priority(), 2);
+    /// assert_eq!(mxpgn.frame.data()[0], 0x43);
+    /// assert_eq!(mxpgn.to_string(), sentence);
+    ///
+    /// // ISO Request for ISO Address Claim, received from address 3.
+    /// let sentence = "$MXPGN,00EA00,6303,00EE00*62";
+    /// let mxpgn = Mxpgn::parse(sentence).unwrap();
+    ///
+    /// assert_eq!(mxpgn.frame.id.pgn(), 59904);
+    /// assert_eq!(mxpgn.frame.id.source(), 3);
+    /// assert_eq!(mxpgn.frame.id.destination(), 255);
+    /// assert_eq!(mxpgn.frame.data(), &[0x00, 0xee, 0x00]);
+    /// assert_eq!(mxpgn.to_string(), sentence);
+    /// ```
+    pub fn parse(sentence: &str) -> Result<Mxpgn, EncapsulatedError> {
+        let fields = fields(sentence, "MXPGN")?;
+        if fields.len() != 3 {
+            return Err(EncapsulatedError::Syntax);
+        }
+
+        let pgn = number(&fields[0], 0x3ffff)?;
+        let attribute = number(&fields[1], 0xffff)? as u16;
+        let mut data = hex::decode(&fields[2]).ok_or(EncapsulatedError::Syntax)?;
+        data.reverse();
+
+        let transmit = attribute & 0x8000 != 0;
+        let priority = (attribute >> 12 & 0x07) as u8;
+        let length = (attribute >> 8 & 0x0f) as usize;
+        let address = attribute as u8;
+        if length != data.len() {
+            return Err(EncapsulatedError::Syntax);
+        }
+
+        let id = if transmit {
+            CanId::new(priority, pgn, 0, address)
+        } else {
+            CanId::new(priority, pgn, address, GLOBAL_ADDRESS)
+        };
+
+        Ok(Mxpgn {
+            transmit,
+            frame: Frame::new(id, &data).ok_or(EncapsulatedError::Syntax)?,
+        })
+    }
+}
+
+impl fmt::Display for Mxpgn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let id = self.frame.id;
+        let address = if self.transmit {
+            id.destination()
+        } else {
+            id.source()
+        };
+        let attribute = (self.transmit as u16) << 15
+            | u16::from(id.priority()) << 12
+            | (self.frame.data().len() as u16) << 8
+            | u16::from(address);
+        let mut data = self.frame.data().to_vec();
+        data.reverse();
+
+        let sentence = sentence(
+            "MX",
+            "PGN",
+            vec![
+                format!("{:06X}", id.pgn()),
+                format!("{:04X}", attribute),
+                hex::encode(&data, ""),
+            ],
+        );
+        write!(f, "{}", sentence)
+    }
+}
+
+/// Parses a sentence and returns its fields if it has the given address.
+fn fields(sentence: &str, address: &str) -> Result<Vec<String>, EncapsulatedError> {
+    let sentence = Sentence::parse(sentence)?;
+    if sentence.start != Start::Parametric || sentence.address() != address {
+        return Err(EncapsulatedError::WrongSentence);
+    }
+
+    Ok(sentence.fields)
+}
+
+/// Parses a field of hex digits holding a number no larger than `max`.
+fn number(field: &str, max: u32) -> Result<u32, EncapsulatedError> {
+    if !hex::is_hex(field) {
+        return Err(EncapsulatedError::Syntax);
+    }
+
+    match u32::from_str_radix(field, 16) {
+        Ok(value) if value <= max => Ok(value),
+        _ => Err(EncapsulatedError::Syntax),
+    }
+}
+
+/// Builds a parametric sentence from its address and fields.
+fn sentence(talker: &str, formatter: &str, fields: Vec<String>) -> Sentence {
+    Sentence {
+        start: Start::Parametric,
+        talker: talker.to_string(),
+        formatter: formatter.to_string(),
+        fields,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A sentence with a valid checksum.
+    fn line(talker: &str, formatter: &str, fields: &[&str]) -> String {
+        let fields = fields.iter().map(|f| f.to_string()).collect();
+        sentence(talker, formatter, fields).to_string()
+    }
+
+    #[test]
+    fn pcdin_round_trip() {
+        let pcdin = Pcdin {
+            timestamp: 0xdeadbeef,
+            message: RawMessage {
+                id: CanId::new(PCDIN_PRIORITY, 130816, 0x23, GLOBAL_ADDRESS),
+                data: (0..20).collect(),
+            },
+        };
+
+        assert_eq!(Pcdin::parse(&pcdin.to_string()), Ok(pcdin));
+    }
+
+    #[test]
+    fn mxpgn_transmit_round_trip() {
+        let mxpgn = Mxpgn {
+            transmit: true,
+            frame: Frame::new(CanId::new(3, 59904, 0, 0x23), &[0x00, 0xee, 0x00]).unwrap(),
+        };
+        let sentence = mxpgn.to_string();
+
+        assert_eq!(sentence, line("MX", "PGN", &["00EA00", "B323", "00EE00"]));
+        assert_eq!(Mxpgn::parse(&sentence), Ok(mxpgn));
+    }
+
+    #[test]
+    fn bad_checksum() {
+        assert_eq!(
+            Pcdin::parse("$PCDIN,01F119,00000000,0F,2AAF00D1067414FF*58"),
+            Err(EncapsulatedError::Checksum)
+        );
+        assert_eq!(
+            Mxpgn::parse("$MXPGN,01F801,2801,C1308AC40C5DE343*18"),
+            Err(EncapsulatedError::Checksum)
+        );
+    }
+
+    #[test]
+    fn wrong_sentence() {
+        let mxpgn = line("MX", "PGN", &["01F801", "2801", "C1308AC40C5DE343"]);
+        let pcdin = line("P", "CDIN", &["01F119", "00000000", "0F", "00"]);
+
+        assert_eq!(Pcdin::parse(&mxpgn), Err(EncapsulatedError::WrongSentence));
+        assert_eq!(Mxpgn::parse(&pcdin), Err(EncapsulatedError::WrongSentence));
+        assert_eq!(
+            Pcdin::parse(&pcdin.replacen('$', "!", 1)),
+            Err(EncapsulatedError::WrongSentence)
+        );
+    }
+
+    #[test]
+    fn rejected_pcdin() {
+        for fields in &[
+            &["01F119", "00000000", "0F"][..],
+            &["01F119", "00000000", "0F", "00", "00"],
+            &["+1F119", "00000000", "0F", "00"],
+            &["40000", "00000000", "0F", "00"],
+            &["01F119", "100000000", "0F", "00"],
+            &["01F119", "00000000", "100", "00"],
+            &["01F119", "00000000", "", "00"],
+            &["01F119", "00000000", "0F", "0"],
+            &["01F119", "00000000", "0F", "0G"],
+        ] {
+            let sentence = line("P", "CDIN", fields);
+            assert_eq!(
+                Pcdin::parse(&sentence),
+                Err(EncapsulatedError::Syntax),
+                "{}",
+                sentence
+            );
+        }
+        assert_eq!(Pcdin::parse("PCDIN,01F119"), Err(EncapsulatedError::Syntax));
+    }
+
+    #[test]
+    fn rejected_mxpgn() {
+        for fields in &[
+            &["01F801", "2801"][..],
+            &["01F801", "2801", "01", "02"],
+            &["01F801", "12801", "C1308AC40C5DE343"],
+            &["01F801", "+801", "C1308AC40C5DE343"],
+            &["01F801", "2701", "C1308AC40C5DE343"],
+            &["01F801", "2901", "C1308AC40C5DE343"],
+            &["01F801", "2901", "C1308AC40C5DE34344"],
+            &["01F801", "2801", "C1308AC40C5DE34"],
+        ] {
+            let sentence = line("MX", "PGN", fields);
+            assert_eq!(
+                Mxpgn::parse(&sentence),
+                Err(EncapsulatedError::Syntax),
+                "{}",
+                sentence
+            );
+        }
+    }
+}
@@ -0,0 +1,645 @@
+//! Encoding of named, typed values into NMEA 2000 payloads.
+//!
+//! This is the reverse of [decoding](../decode/index.html). Each field of a
+//! [Pgn](../struct.Pgn.html) definition is given a value by name, which is converted back to the
+//! raw value sent on the wire by undoing the field's `multiplier` and `offset`.
+
+use std::error::Error;
+use std::fmt;
+
+use bits::{mask, BitWriter};
+use can::{CanId, RawMessage};
+use decode::{Message, Value};
+use registry;
+use {Field, FieldType, Pgn};
+
+/// Errors which may occur while encoding a payload.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncodeError {
+    /// There is no definition for the PGN.
+    UnknownPgn(u32),
+    /// The value given for the named field is of a type the field cannot hold.
+    TypeMismatch(&'static str),
+    /// The value given for the named field does not fit in the field.
+    ValueOutOfRange(&'static str),
+    /// The name given for the named lookup field is not in its lookup table.
+    UnknownLookupName(&'static str),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EncodeError::UnknownPgn(pgn) => write!(f, "no definition for PGN {}", pgn),
+            EncodeError::TypeMismatch(name) => {
+                write!(f, "value of the wrong type for field \"{}\"", name)
+            }
+            EncodeError::ValueOutOfRange(name) => {
+                write!(f, "value out of range for field \"{}\"", name)
+            }
+            EncodeError::UnknownLookupName(name) => {
+                write!(f, "name not in the lookup table of field \"{}\"", name)
+            }
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
+/// Encodes a payload using the given PGN definition.
+///
+/// Fields which are given no value are sent as not available, except for fields with a
+/// `match_value`, which get that value, single bit fields, which are sent as zero, and the count
+/// of repeating fields, which is the number of repetitions given. Reserved bits are set to ones.
+/// The values of repeating fields are taken in order, one occurrence of each name per repetition.
+/// A `Value::String` may be given for a lookup field, in which case it is looked up by name in the
+/// field's lookup table.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::encode::encode;
+/// use libnmea::{registry, Value};
+///
+/// let request = &registry::get(59904)[0];
+/// let data = encode(request, &[("PGN", Value::Integer(60928))]).unwrap();
+///
+/// assert_eq!(data, vec![0x00, 0xee, 0x00]);
+/// ```
+///
+/// Flags left out are cleared, and the payload ends after the repetitions given:
+///
+/// ```
+/// use libnmea::decode::decode_fields;
+/// use libnmea::encode::{encode, EncodeError};
+/// use libnmea::{registry, Value};
+///
+/// let engine = &registry::get(127489)[0];
+/// let data = encode(engine, &[
+///     ("Instance", Value::Integer(0)),
+///     ("Low Oil Pressure", Value::String("Yes".to_string())),
+/// ]).unwrap();
+/// let raised: Vec<&str> = decode_fields(engine, &data)
+///     .iter()
+///     .filter(|f| f.value == Value::Lookup(1, Some("Yes")))
+///     .map(|f| f.name)
+///     .collect();
+///
+/// assert_eq!(raised, vec!["Low Oil Pressure"]);
+/// assert_eq!(
+///     encode(engine, &[("Check Engine", Value::Reserved)]),
+///     Err(EncodeError::ValueOutOfRange("Check Engine"))
+/// );
+/// assert_eq!(
+///     encode(engine, &[("Check Engine", Value::Lookup(2, None))]),
+///     Err(EncodeError::ValueOutOfRange("Check Engine"))
+/// );
+///
+/// let gnss = &registry::get(129029)[0];
+/// let data = encode(gnss, &[("Number of SVs", Value::Integer(9))]).unwrap();
+/// let fields = decode_fields(gnss, &data);
+///
+/// assert_eq!(data.len(), 43);
+/// assert_eq!(fields.last().unwrap().name, "Reference Stations");
+/// assert_eq!(fields.last().unwrap().value, Value::Integer(0));
+/// ```
+pub fn encode(pgn: &Pgn, values: &[(&str, Value)]) -> Result<Vec<u8>, EncodeError> {
+    let repeating = pgn.repeating_fields as usize;
+    let split = pgn.fields.len().saturating_sub(repeating);
+    let (fixed, repeated) = pgn.fields.split_at(split);
+    let repetitions = repeated
+        .iter()
+        .map(|field| values.iter().filter(|v| v.0 == field.name).count())
+        .max()
+        .unwrap_or(0);
+    let count = Value::Integer(repetitions as i64);
+
+    let mut writer = BitWriter::new(pgn.size as usize);
+    // Variable length strings move every field after them, as when decoding.
+    let mut shift: isize = 0;
+    let mut end = 0;
+
+    for field in fixed {
+        let value = values
+            .iter()
+            .find(|v| v.0 == field.name)
+            .map(|v| &v.1)
+            .or_else(|| field.repeat_count.then_some(&count));
+        encode_field(field, value, &mut writer, &mut shift, 0)?;
+        end = field_end(field, shift, 0);
+    }
+
+    if let (Some(first), Some(last)) = (repeated.first(), repeated.last()) {
+        let span = (last.start + last.size - first.start) as usize;
+
+        for repetition in 0..repetitions {
+            for field in repeated {
+                let value = values
+                    .iter()
+                    .filter(|v| v.0 == field.name)
+                    .nth(repetition)
+                    .map(|v| &v.1);
+                encode_field(field, value, &mut writer, &mut shift, repetition * span)?;
+                end = field_end(field, shift, repetition * span);
+            }
+        }
+    }
+
+    let mut data = writer.into_bytes();
+    // The nominal size holds one repetition and strings of their nominal length, so payloads
+    // whose length varies end with the last field written.
+    let variable = pgn
+        .fields
+        .iter()
+        .any(|field| field.field_type == Some(FieldType::PascalString));
+    if repeating > 0 || variable {
+        data.truncate(end.div_ceil(8));
+    }
+
+    Ok(data)
+}
+
+/// Encodes a decoded message back into a payload and CAN identifier. The definition with the
+/// message's name is used, which makes this the exact reverse of
+/// [decode](../decode/fn.decode.html).
+pub fn encode_message(message: &Message) -> Result<RawMessage, EncodeError> {
+    let definitions = registry::get(message.pgn);
+    let definition = definitions
+        .iter()
+        .find(|d| d.name == message.name)
+        .or_else(|| definitions.first())
+        .ok_or(EncodeError::UnknownPgn(message.pgn))?;
+
+    let values: Vec<(&str, Value)> = message
+        .fields
+        .iter()
+        .map(|f| (f.name, f.value.clone()))
+        .collect();
+
+    Ok(RawMessage {
+        id: CanId::new(
+            message.priority,
+            message.pgn,
+            message.source,
+            message.destination,
+        ),
+        data: encode(definition, &values)?,
+    })
+}
+
+fn field_end(field: &Field, shift: isize, base: usize) -> usize {
+    ((field.start + field.size) as isize + shift) as usize + base
+}
+
+fn encode_field(
+    field: &Field,
+    value: Option<&Value>,
+    writer: &mut BitWriter,
+    shift: &mut isize,
+    base: usize,
+) -> Result<(), EncodeError> {
+    let start = (field.start as isize + *shift) as usize + base;
+    let size = field.size as usize;
+
+    let value = match value {
+        Some(value) => value,
+        None => match field.match_value {
+            Some(raw) => {
+                writer.write_unsigned(start, size, raw);
+                return Ok(());
+            }
+            None => &Value::NotAvailable,
+        },
+    };
+
+    match field.field_type {
+        Some(FieldType::NotUsed) => {}
+        Some(FieldType::Float) => {
+            let number = match *value {
+                Value::Decimal(d) => d,
+                Value::Integer(i) => i as f64,
+                Value::NotAvailable => return Ok(()),
+                _ => return Err(EncodeError::TypeMismatch(field.name)),
+            };
+            match size {
+                32 => writer.write_unsigned(start, size, u64::from((number as f32).to_bits())),
+                64 => writer.write_unsigned(start, size, number.to_bits()),
+                _ => return Err(EncodeError::TypeMismatch(field.name)),
+            }
+        }
+        Some(FieldType::AsciiString) | Some(FieldType::FixedString) => {
+            let text = text(field, value)?;
+            let mut bytes: Vec<u8> = text.bytes().take(size / 8).collect();
+            bytes.resize(size / 8, 0xff);
+            writer.write_bytes(start, &bytes);
+        }
+        Some(FieldType::WideString) => {
+            let text = text(field, value)?;
+            let mut bytes: Vec<u8> = text
+                .encode_utf16()
+                .flat_map(|u| vec![u as u8, (u >> 8) as u8])
+                .take(size / 8)
+                .collect();
+            bytes.resize(size / 8, 0xff);
+            writer.write_bytes(start, &bytes);
+        }
+        Some(FieldType::PascalString) => {
+            // Sent as ASCII, with the length counting the length and control bytes.
+            let text = text(field, value)?;
+            let bytes: Vec<u8> = text.bytes().take(253).collect();
+            let length = bytes.len() + 2;
+            writer.write_bytes(start, &[length as u8, 1]);
+            writer.write_bytes(start + 16, &bytes);
+            *shift += (length * 8) as isize - size as isize;
+        }
+        Some(FieldType::Variable) => match *value {
+            Value::Bytes(ref bytes) => writer.write_bytes(start, bytes),
+            Value::NotAvailable => {}
+            _ => return Err(EncodeError::TypeMismatch(field.name)),
+        },
+        _ => {
+            let raw = raw_value(field, value)?;
+            writer.write_unsigned(start, size, raw);
+        }
+    }
+
+    Ok(())
+}
+
+fn text<'a>(field: &Field, value: &'a Value) -> Result<&'a str, EncodeError> {
+    match *value {
+        Value::String(ref text) => Ok(text),
+        Value::NotAvailable => Ok(""),
+        _ => Err(EncodeError::TypeMismatch(field.name)),
+    }
+}
+
+/// Converts a value to the raw bits of an integer field, checking that it fits and does not
+/// collide with the values reserved at the top of the field's range.
+fn raw_value(field: &Field, value: &Value) -> Result<u64, EncodeError> {
+    let size = field.size as usize;
+    let max = if field.signed {
+        mask(size.saturating_sub(1))
+    } else {
+        mask(size)
+    };
+    let sentinels = if field.field_type == Some(FieldType::Lookup) || size < 2 {
+        0
+    } else if size < 4 {
+        1
+    } else {
+        3
+    };
+
+    let scaled = match *value {
+        // Single bit fields have no value to spare for data that is not available.
+        Value::NotAvailable if size < 2 => return Ok(0),
+        Value::NotAvailable => return Ok(max),
+        Value::OutOfRange if sentinels > 1 => return Ok(max - 1),
+        Value::Reserved if sentinels > 2 => return Ok(max - 2),
+        Value::OutOfRange | Value::Reserved => {
+            return Err(EncodeError::ValueOutOfRange(field.name))
+        }
+        Value::Lookup(raw, _) => return fits(field, raw),
+        Value::String(ref name) if field.field_type == Some(FieldType::Lookup) => {
+            let raw = field
+                .lookup
+                .and_then(|l| l.value_of(name))
+                .ok_or(EncodeError::UnknownLookupName(field.name))?;
+            return fits(field, u64::from(raw));
+        }
+        Value::Integer(i) if field.multiplier == 0.0 => i128::from(i),
+        Value::Integer(i) => (i as f64 / field.multiplier).round() as i128,
+        Value::Decimal(d) if field.multiplier == 0.0 => d.round() as i128,
+        Value::Decimal(d) => (d / field.multiplier).round() as i128,
+        _ => return Err(EncodeError::TypeMismatch(field.name)),
+    };
+    let raw = scaled - i128::from(field.offset);
+
+    let (low, high) = if field.signed {
+        (-i128::from(max) - 1, i128::from(max) - sentinels)
+    } else {
+        (0, i128::from(max) - sentinels)
+    };
+    if raw < low || raw > high {
+        return Err(EncodeError::ValueOutOfRange(field.name));
+    }
+
+    Ok(raw as u64 & mask(size))
+}
+
+/// Checks that a raw value given as is fits in the field.
+fn fits(field: &Field, raw: u64) -> Result<u64, EncodeError> {
+    if raw > mask(field.size as usize) {
+        return Err(EncodeError::ValueOutOfRange(field.name));
+    }
+
+    Ok(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use can::CanId;
+    use decode::{decode, decode_fields};
+    use lookup::YES_NO;
+    use PgnCategory;
+
+    fn pgn(fields: Vec<Field>) -> Pgn {
+        Pgn {
+            name: "Test",
+            category: PgnCategory::General,
+            pgn: 65280,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields,
+        }
+    }
+
+    fn field(name: &'static str, field_type: FieldType, start: u16, size: u16) -> Field {
+        Field {
+            name,
+            field_type: Some(field_type),
+            start,
+            size,
+            ..Default::default()
+        }
+    }
+
+    fn round_trip(pgn: &Pgn, values: &[(&str, Value)]) -> Vec<(&'static str, Value)> {
+        let data = encode(pgn, values).unwrap();
+        decode_fields(pgn, &data)
+            .into_iter()
+            .map(|f| (f.name, f.value))
+            .collect()
+    }
+
+    /// Every definition survives being encoded with no values, decoded, and encoded again.
+    #[test]
+    fn every_definition_round_trips() {
+        for definition in registry::registry().iter() {
+            let data = encode(definition, &[]).unwrap();
+            let values: Vec<(&str, Value)> = decode_fields(definition, &data)
+                .into_iter()
+                .map(|f| (f.name, f.value))
+                .collect();
+
+            assert_eq!(
+                encode(definition, &values).as_ref(),
+                Ok(&data),
+                "{} {}",
+                definition.pgn,
+                definition.name
+            );
+        }
+    }
+
+    #[test]
+    fn scaled_and_signed_values() {
+        let pgn = pgn(vec![
+            Field {
+                multiplier: 0.01,
+                ..field("Voltage", FieldType::Decimal, 0, 16)
+            },
+            Field {
+                signed: true,
+                multiplier: 0.1,
+                ..field("Current", FieldType::Decimal, 16, 16)
+            },
+            Field {
+                offset: -40,
+                ..field("Temperature", FieldType::Integer, 32, 8)
+            },
+        ]);
+
+        assert_eq!(
+            round_trip(
+                &pgn,
+                &[
+                    ("Voltage", Value::Decimal(12.34)),
+                    ("Current", Value::Decimal(-5.5)),
+                    ("Temperature", Value::Integer(-10)),
+                ]
+            ),
+            vec![
+                ("Voltage", Value::Decimal(12.34)),
+                ("Current", Value::Decimal(-5.5)),
+                ("Temperature", Value::Integer(-10)),
+            ]
+        );
+    }
+
+    #[test]
+    fn sentinels_round_trip() {
+        let pgn = pgn(vec![
+            field("Wide", FieldType::Integer, 0, 8),
+            field("Narrow", FieldType::Integer, 8, 2),
+            Field {
+                signed: true,
+                ..field("Signed", FieldType::Integer, 16, 16)
+            },
+        ]);
+
+        for value in &[Value::NotAvailable, Value::OutOfRange, Value::Reserved] {
+            let values = round_trip(&pgn, &[("Wide", value.clone()), ("Signed", value.clone())]);
+            assert_eq!(values[0], ("Wide", value.clone()));
+            assert_eq!(values[1], ("Narrow", Value::NotAvailable));
+            assert_eq!(values[2], ("Signed", value.clone()));
+        }
+
+        assert_eq!(
+            encode(&pgn, &[("Narrow", Value::OutOfRange)]),
+            Err(EncodeError::ValueOutOfRange("Narrow"))
+        );
+    }
+
+    #[test]
+    fn values_colliding_with_sentinels() {
+        let pgn = pgn(vec![
+            field("Wide", FieldType::Integer, 0, 8),
+            field("Narrow", FieldType::Integer, 8, 3),
+            Field {
+                signed: true,
+                ..field("Signed", FieldType::Integer, 16, 8)
+            },
+        ]);
+
+        assert!(encode(&pgn, &[("Wide", Value::Integer(252))]).is_ok());
+        assert_eq!(
+            encode(&pgn, &[("Wide", Value::Integer(253))]),
+            Err(EncodeError::ValueOutOfRange("Wide"))
+        );
+        assert_eq!(
+            encode(&pgn, &[("Wide", Value::Integer(-1))]),
+            Err(EncodeError::ValueOutOfRange("Wide"))
+        );
+        assert!(encode(&pgn, &[("Narrow", Value::Integer(6))]).is_ok());
+        assert_eq!(
+            encode(&pgn, &[("Narrow", Value::Integer(7))]),
+            Err(EncodeError::ValueOutOfRange("Narrow"))
+        );
+        assert!(encode(&pgn, &[("Signed", Value::Integer(-128))]).is_ok());
+        assert!(encode(&pgn, &[("Signed", Value::Integer(124))]).is_ok());
+        assert_eq!(
+            encode(&pgn, &[("Signed", Value::Integer(125))]),
+            Err(EncodeError::ValueOutOfRange("Signed"))
+        );
+        assert_eq!(
+            encode(&pgn, &[("Signed", Value::Integer(-129))]),
+            Err(EncodeError::ValueOutOfRange("Signed"))
+        );
+    }
+
+    #[test]
+    fn flags_and_lookups() {
+        let pgn = pgn(vec![
+            Field {
+                lookup: Some(&YES_NO),
+                ..field("Flag", FieldType::Lookup, 0, 1)
+            },
+            Field {
+                lookup: Some(&YES_NO),
+                ..field("Unset", FieldType::Lookup, 1, 1)
+            },
+            Field {
+                lookup: Some(&YES_NO),
+                ..field("State", FieldType::Lookup, 8, 4)
+            },
+        ]);
+
+        assert_eq!(
+            round_trip(
+                &pgn,
+                &[
+                    ("Flag", Value::String("yes".to_string())),
+                    ("State", Value::Lookup(1, None)),
+                ]
+            ),
+            vec![
+                ("Flag", Value::Lookup(1, Some("Yes"))),
+                ("Unset", Value::Lookup(0, Some("No"))),
+                ("State", Value::Lookup(1, Some("Yes"))),
+            ]
+        );
+        assert_eq!(
+            encode(&pgn, &[("Flag", Value::String("Maybe".to_string()))]),
+            Err(EncodeError::UnknownLookupName("Flag"))
+        );
+        assert_eq!(
+            encode(&pgn, &[("State", Value::Lookup(16, None))]),
+            Err(EncodeError::ValueOutOfRange("State"))
+        );
+    }
+
+    #[test]
+    fn type_mismatches() {
+        let pgn = pgn(vec![
+            field("Number", FieldType::Integer, 0, 8),
+            field("Name", FieldType::AsciiString, 8, 32),
+            field("Data", FieldType::Variable, 40, 0),
+            field("Float", FieldType::Float, 8, 16),
+        ]);
+
+        assert_eq!(
+            encode(&pgn, &[("Number", Value::String("1".to_string()))]),
+            Err(EncodeError::TypeMismatch("Number"))
+        );
+        assert_eq!(
+            encode(&pgn, &[("Name", Value::Integer(1))]),
+            Err(EncodeError::TypeMismatch("Name"))
+        );
+        assert_eq!(
+            encode(&pgn, &[("Data", Value::Integer(1))]),
+            Err(EncodeError::TypeMismatch("Data"))
+        );
+        assert_eq!(
+            encode(&pgn, &[("Float", Value::Decimal(1.0))]),
+            Err(EncodeError::TypeMismatch("Float"))
+        );
+    }
+
+    #[test]
+    fn strings_and_floats() {
+        let pgn = pgn(vec![
+            field("Name", FieldType::AsciiString, 0, 64),
+            field("Wide", FieldType::WideString, 64, 64),
+            field("Single", FieldType::Float, 128, 32),
+            field("Double", FieldType::Float, 160, 64),
+        ]);
+
+        assert_eq!(
+            round_trip(
+                &pgn,
+                &[
+                    ("Name", Value::String("Too long to fit".to_string())),
+                    ("Wide", Value::String("\u{e9}t\u{e9}".to_string())),
+                    ("Single", Value::Decimal(-1.25)),
+                    ("Double", Value::Integer(3)),
+                ]
+            ),
+            vec![
+                ("Name", Value::String("Too long".to_string())),
+                ("Wide", Value::String("\u{e9}t\u{e9}".to_string())),
+                ("Single", Value::Decimal(-1.25)),
+                ("Double", Value::Decimal(3.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn repeating_pascal_strings() {
+        let mut pgn = pgn(vec![
+            Field {
+                repeat_count: true,
+                ..field("Count", FieldType::Integer, 0, 8)
+            },
+            field("Name", FieldType::PascalString, 8, 16),
+            field("Level", FieldType::Integer, 24, 8),
+        ]);
+        pgn.repeating_fields = 2;
+
+        let data = encode(
+            &pgn,
+            &[
+                ("Name", Value::String("abc".to_string())),
+                ("Level", Value::Integer(1)),
+                ("Name", Value::String(String::new())),
+                ("Level", Value::Integer(2)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(data, vec![2, 5, 1, b'a', b'b', b'c', 1, 2, 1, 2]);
+        assert_eq!(round_trip(&pgn, &[]), vec![("Count", Value::Integer(0))]);
+    }
+
+    #[test]
+    fn rudder_command_round_trip() {
+        // Command to 0x23 setting the Angle Order (field 4) of Rudder (127245) to 0.
+        let id = CanId::new(3, 126208, 0x01, 0x23);
+        let data = [0x01, 0x0d, 0xf1, 0x01, 0xf8, 0x01, 0x04, 0x00, 0x00];
+        let message = decode(id, &data).unwrap();
+        let raw = encode_message(&message).unwrap();
+
+        assert_eq!(message.get("PGN"), Some(&Value::Integer(127245)));
+        assert_eq!(raw.id, id);
+        assert_eq!(raw.data, data.to_vec());
+    }
+
+    #[test]
+    fn unknown_pgn() {
+        let message = Message {
+            name: "Unknown",
+            pgn: 130000,
+            priority: 2,
+            source: 0x01,
+            destination: 0xff,
+            fields: Vec::new(),
+        };
+
+        assert_eq!(
+            encode_message(&message),
+            Err(EncodeError::UnknownPgn(130000))
+        );
+    }
+}
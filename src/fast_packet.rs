@@ -0,0 +1,459 @@
+//! NMEA 2000 Fast Packet reassembly.
+//!
+//! PGNs with payloads of up to 223 bytes are sent as a series of frames. The first byte of every
+//! frame holds a 3-bit sequence counter, which is the same for all frames of one message, and a
+//! 5-bit frame index. The first frame carries the total length of the payload in its second byte
+//! followed by 6 bytes of data, and every frame after it carries 7 bytes of data.
+//!
+//! The [assembler](struct.FastPacketAssembler.html) puts received frames back together, and the
+//! [fragmenter](struct.FastPacketFragmenter.html) splits messages up for sending.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use can::{Frame, RawMessage};
+use registry;
+
+/// Largest payload which can be sent as a Fast Packet.
+pub const MAX_SIZE: usize = 6 + 31 * 7;
+
+/// How long an incomplete message is kept before it is discarded. Consecutive frames of a Fast
+/// Packet are normally sent well within this time.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(750);
+
+/// Whether a PGN is sent as a Fast Packet. This is the case for the proprietary Fast Packet
+/// ranges and any PGN whose definition is larger than a single frame.
+pub fn is_fast_packet(pgn: u32) -> bool {
+    pgn == 126720
+        || (130816..=131071).contains(&pgn)
+        || registry::get(pgn).iter().any(|p| p.size > 8)
+}
+
+/// Reassembles Fast Packet frames into complete messages.
+///
+/// Messages are tracked separately for each source address and PGN, so interleaved messages from
+/// different devices are reassembled independently. Frames may arrive in any order. A message is
+/// discarded if a frame with a different sequence counter arrives before it is complete, if a new
+/// first frame arrives for it, or if it is not completed within the timeout.
+///
+/// Times passed to the assembler only need to be consistent with each other, so a monotonic clock
+/// or the timestamps from a log file may be used.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use libnmea::can::{CanId, Frame};
+/// use libnmea::fast_packet::FastPacketAssembler;
+///
+/// let id = CanId::new(6, 126996, 0x23, 0xff);
+/// let mut assembler = FastPacketAssembler::new();
+///
+/// let first = Frame::new(id, &[0x40, 9, 1, 2, 3, 4, 5, 6]).unwrap();
+/// let second = Frame::new(id, &[0x41, 7, 8, 9, 0xff, 0xff, 0xff, 0xff]).unwrap();
+///
+/// assert_eq!(assembler.push(&first, Duration::from_millis(0)), None);
+///
+/// let message = assembler.push(&second, Duration::from_millis(5)).unwrap();
+/// assert_eq!(message.data, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+#[derive(Debug)]
+pub struct FastPacketAssembler {
+    timeout: Duration,
+    sessions: HashMap<(u8, u32), Session>,
+}
+
+#[derive(Debug)]
+struct Session {
+    frame: Frame,
+    sequence: u8,
+    started: Duration,
+    length: Option<usize>,
+    received: u32,
+    data: Vec<u8>,
+}
+
+impl Session {
+    fn frames(&self) -> Option<usize> {
+        self.length
+            .map(|length| 1 + length.saturating_sub(6).div_ceil(7))
+    }
+
+    fn is_complete(&self) -> bool {
+        match self.frames() {
+            Some(frames) => self.received.count_ones() as usize == frames,
+            None => false,
+        }
+    }
+}
+
+impl FastPacketAssembler {
+    /// Creates an assembler using the [default timeout](constant.DEFAULT_TIMEOUT.html).
+    pub fn new() -> FastPacketAssembler {
+        FastPacketAssembler::with_timeout(DEFAULT_TIMEOUT)
+    }
+
+    /// Creates an assembler which discards incomplete messages after `timeout`.
+    pub fn with_timeout(timeout: Duration) -> FastPacketAssembler {
+        FastPacketAssembler {
+            timeout,
+            sessions: HashMap::new(),
+        }
+    }
+
+    /// Adds a frame received at time `now`, returning the complete message if this frame was the
+    /// last one missing. Frames which are malformed or belong to no message are ignored.
+    pub fn push(&mut self, frame: &Frame, now: Duration) -> Option<RawMessage> {
+        let data = frame.data();
+        if data.is_empty() {
+            return None;
+        }
+
+        let sequence = data[0] >> 5;
+        let index = (data[0] & 0x1f) as usize;
+        let key = (frame.id.source(), frame.id.pgn());
+
+        let stale = match self.sessions.get(&key) {
+            Some(session) => {
+                now.saturating_sub(session.started) > self.timeout
+                    || session.sequence != sequence
+                    || (index == 0 && session.length.is_some())
+            }
+            None => false,
+        };
+        if stale {
+            self.sessions.remove(&key);
+        }
+
+        let session = self.sessions.entry(key).or_insert_with(|| Session {
+            frame: *frame,
+            sequence,
+            started: now,
+            length: None,
+            received: 0,
+            data: vec![0xff; MAX_SIZE],
+        });
+
+        if session.received & (1 << index) != 0 {
+            return None;
+        }
+
+        if index == 0 {
+            if data.len() < 2 || data[1] as usize > MAX_SIZE {
+                return None;
+            }
+            session.frame = *frame;
+            session.length = Some(data[1] as usize);
+            copy(&mut session.data[..6], &data[2..]);
+        } else {
+            let offset = 6 + (index - 1) * 7;
+            copy(&mut session.data[offset..offset + 7], &data[1..]);
+        }
+        session.received |= 1 << index;
+
+        // Drop any frames received past the end of the message before its length was known.
+        if let Some(frames) = session.frames() {
+            session.received &= (1u64 << frames).wrapping_sub(1) as u32;
+        }
+
+        if !session.is_complete() {
+            return None;
+        }
+
+        let session = self.sessions.remove(&key)?;
+        let length = session.length?;
+        Some(RawMessage {
+            id: session.frame.id,
+            data: session.data[..length].to_vec(),
+        })
+    }
+
+    /// Discards incomplete messages which have timed out by time `now`, returning how many were
+    /// discarded.
+    pub fn expire(&mut self, now: Duration) -> usize {
+        let timeout = self.timeout;
+        let before = self.sessions.len();
+        self.sessions
+            .retain(|_, session| now.saturating_sub(session.started) <= timeout);
+        before - self.sessions.len()
+    }
+
+    /// Number of messages which have been started but are not yet complete.
+    pub fn pending(&self) -> usize {
+        self.sessions.len()
+    }
+}
+
+impl Default for FastPacketAssembler {
+    fn default() -> FastPacketAssembler {
+        FastPacketAssembler::new()
+    }
+}
+
+/// Splits messages into Fast Packet frames for sending.
+///
+/// The sequence counter is kept separately for each PGN and source address, and advances with
+/// every message so that receivers can tell consecutive messages apart.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::can::{CanId, RawMessage};
+/// use libnmea::fast_packet::FastPacketFragmenter;
+///
+/// let message = RawMessage {
+///     id: CanId::new(6, 126996, 0x23, 0xff),
+///     data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9],
+/// };
+/// let mut fragmenter = FastPacketFragmenter::new();
+///
+/// let frames = fragmenter.fragment(&message).unwrap();
+/// assert_eq!(frames[0].data(), &[0x00, 9, 1, 2, 3, 4, 5, 6]);
+/// assert_eq!(frames[1].data(), &[0x01, 7, 8, 9, 0xff, 0xff, 0xff, 0xff]);
+///
+/// let frames = fragmenter.fragment(&message).unwrap();
+/// assert_eq!(frames[0].data()[0], 0x20);
+/// ```
+#[derive(Debug, Default)]
+pub struct FastPacketFragmenter {
+    sequences: HashMap<(u8, u32), u8>,
+}
+
+impl FastPacketFragmenter {
+    /// Creates a fragmenter with every sequence counter starting at 0.
+    pub fn new() -> FastPacketFragmenter {
+        FastPacketFragmenter::default()
+    }
+
+    /// Splits a message into frames, or returns `None` if it is larger than
+    /// [MAX_SIZE](constant.MAX_SIZE.html). Every frame is padded to 8 bytes with `0xff`.
+    pub fn fragment(&mut self, message: &RawMessage) -> Option<Vec<Frame>> {
+        let data = &message.data;
+        if data.len() > MAX_SIZE {
+            return None;
+        }
+
+        let counter = self
+            .sequences
+            .entry((message.id.source(), message.id.pgn()))
+            .or_insert(0);
+        let sequence = *counter << 5;
+        *counter = (*counter + 1) & 0x07;
+
+        let mut frames = Vec::new();
+        let mut first = [0xff; 8];
+        first[0] = sequence;
+        first[1] = data.len() as u8;
+        copy(&mut first[2..], data);
+        frames.push(Frame::new(message.id, &first)?);
+
+        for (index, chunk) in data.get(6..).unwrap_or(&[]).chunks(7).enumerate() {
+            let mut frame = [0xff; 8];
+            frame[0] = sequence | (index as u8 + 1);
+            copy(&mut frame[1..], chunk);
+            frames.push(Frame::new(message.id, &frame)?);
+        }
+
+        Some(frames)
+    }
+}
+
+fn copy(target: &mut [u8], source: &[u8]) {
+    let len = target.len().min(source.len());
+    target[..len].copy_from_slice(&source[..len]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use can::CanId;
+
+    fn frame(source: u8, data: &[u8]) -> Frame {
+        Frame::new(CanId::new(6, 126996, source, 0xff), data).unwrap()
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn out_of_order_frames() {
+        let mut assembler = FastPacketAssembler::new();
+
+        assert_eq!(assembler.push(&frame(1, &[0x22, 14, 15, 16]), ms(0)), None);
+        assert_eq!(
+            assembler.push(&frame(1, &[0x21, 7, 8, 9, 10, 11, 12, 13]), ms(1)),
+            None
+        );
+        let message = assembler
+            .push(&frame(1, &[0x20, 16, 1, 2, 3, 4, 5, 6]), ms(2))
+            .unwrap();
+
+        assert_eq!(message.data, (1..=16).collect::<Vec<u8>>());
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn frames_past_the_end_are_dropped() {
+        let mut assembler = FastPacketAssembler::new();
+
+        assert_eq!(
+            assembler.push(&frame(1, &[0x05, 0, 0, 0, 0, 0, 0, 0]), ms(0)),
+            None
+        );
+        let message = assembler
+            .push(&frame(1, &[0x00, 3, 1, 2, 3]), ms(1))
+            .unwrap();
+
+        assert_eq!(message.data, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn duplicate_frame_is_ignored() {
+        let mut assembler = FastPacketAssembler::new();
+
+        assert_eq!(
+            assembler.push(&frame(1, &[0x00, 9, 1, 2, 3, 4, 5, 6]), ms(0)),
+            None
+        );
+        assert_eq!(
+            assembler.push(&frame(1, &[0x00, 9, 1, 2, 3, 4, 5, 6]), ms(1)),
+            None
+        );
+        assert!(assembler.push(&frame(1, &[0x01, 7, 8, 9]), ms(2)).is_some());
+    }
+
+    #[test]
+    fn interleaved_sources() {
+        let mut assembler = FastPacketAssembler::new();
+
+        assert_eq!(
+            assembler.push(&frame(1, &[0x00, 9, 1, 2, 3, 4, 5, 6]), ms(0)),
+            None
+        );
+        assert_eq!(
+            assembler.push(&frame(2, &[0x40, 9, 9, 8, 7, 6, 5, 4]), ms(1)),
+            None
+        );
+        assert_eq!(assembler.pending(), 2);
+
+        let second = assembler.push(&frame(2, &[0x41, 3, 2, 1]), ms(2)).unwrap();
+        let first = assembler.push(&frame(1, &[0x01, 7, 8, 9]), ms(3)).unwrap();
+
+        assert_eq!(first.id.source(), 1);
+        assert_eq!(first.data, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(second.id.source(), 2);
+        assert_eq!(second.data, vec![9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn sequence_change_discards_message() {
+        let mut assembler = FastPacketAssembler::new();
+
+        assert_eq!(
+            assembler.push(&frame(1, &[0x00, 9, 1, 2, 3, 4, 5, 6]), ms(0)),
+            None
+        );
+        assert_eq!(assembler.push(&frame(1, &[0x21, 7, 8, 9]), ms(1)), None);
+        assert_eq!(assembler.push(&frame(1, &[0x01, 7, 8, 9]), ms(2)), None);
+    }
+
+    #[test]
+    fn new_first_frame_restarts_message() {
+        let mut assembler = FastPacketAssembler::new();
+
+        assert_eq!(
+            assembler.push(&frame(1, &[0x00, 9, 1, 2, 3, 4, 5, 6]), ms(0)),
+            None
+        );
+        assert_eq!(
+            assembler.push(&frame(1, &[0x00, 9, 6, 5, 4, 3, 2, 1]), ms(1)),
+            None
+        );
+        let message = assembler.push(&frame(1, &[0x01, 7, 8, 9]), ms(2)).unwrap();
+
+        assert_eq!(message.data, vec![6, 5, 4, 3, 2, 1, 7, 8, 9]);
+    }
+
+    #[test]
+    fn timeout_discards_message() {
+        let mut assembler = FastPacketAssembler::with_timeout(ms(100));
+
+        assert_eq!(
+            assembler.push(&frame(1, &[0x00, 9, 1, 2, 3, 4, 5, 6]), ms(0)),
+            None
+        );
+        assert_eq!(assembler.push(&frame(1, &[0x01, 7, 8, 9]), ms(101)), None);
+        assert_eq!(assembler.pending(), 1);
+    }
+
+    #[test]
+    fn expire() {
+        let mut assembler = FastPacketAssembler::with_timeout(ms(100));
+
+        assembler.push(&frame(1, &[0x00, 9, 1, 2, 3, 4, 5, 6]), ms(0));
+        assembler.push(&frame(2, &[0x00, 9, 1, 2, 3, 4, 5, 6]), ms(50));
+
+        assert_eq!(assembler.expire(ms(100)), 0);
+        assert_eq!(assembler.expire(ms(101)), 1);
+        assert_eq!(assembler.pending(), 1);
+        assert_eq!(assembler.expire(ms(151)), 1);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn malformed_frames() {
+        let mut assembler = FastPacketAssembler::new();
+
+        assert_eq!(assembler.push(&frame(1, &[]), ms(0)), None);
+        assert_eq!(assembler.push(&frame(1, &[0x00]), ms(0)), None);
+        assert_eq!(
+            assembler.push(&frame(2, &[0x00, 224, 1, 2, 3, 4, 5, 6]), ms(0)),
+            None
+        );
+        assert_eq!(assembler.push(&frame(2, &[0x01, 7, 8, 9]), ms(1)), None);
+    }
+
+    #[test]
+    fn fragment_round_trip() {
+        let message = RawMessage {
+            id: CanId::new(6, 126996, 0x23, 0xff),
+            data: (0..MAX_SIZE).map(|i| i as u8).collect(),
+        };
+        let mut fragmenter = FastPacketFragmenter::new();
+        let mut assembler = FastPacketAssembler::new();
+
+        let frames = fragmenter.fragment(&message).unwrap();
+        assert_eq!(frames.len(), 32);
+
+        let mut reassembled = None;
+        for frame in frames.iter().rev() {
+            reassembled = assembler.push(frame, ms(0));
+        }
+        assert_eq!(reassembled, Some(message));
+    }
+
+    #[test]
+    fn fragment_too_large() {
+        let message = RawMessage {
+            id: CanId::new(6, 126996, 0x23, 0xff),
+            data: vec![0; MAX_SIZE + 1],
+        };
+
+        assert_eq!(FastPacketFragmenter::new().fragment(&message), None);
+    }
+
+    #[test]
+    fn fragment_sequence_wraps() {
+        let message = RawMessage {
+            id: CanId::new(6, 126996, 0x23, 0xff),
+            data: vec![0; 9],
+        };
+        let mut fragmenter = FastPacketFragmenter::new();
+
+        let sequences: Vec<u8> = (0..9)
+            .map(|_| fragmenter.fragment(&message).unwrap()[0].data()[0] >> 5)
+            .collect();
+        assert_eq!(sequences, vec![0, 1, 2, 3, 4, 5, 6, 7, 0]);
+    }
+}
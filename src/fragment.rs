@@ -0,0 +1,134 @@
+//! Splitting of encoded messages into CAN frames for sending.
+//!
+//! Messages which fit in a single frame are sent as they are. Larger messages of Fast Packet PGNs
+//! are sent as a [Fast Packet](../fast_packet/index.html), and anything else, including messages
+//! too large for Fast Packet, with the [transport protocol](../transport/index.html).
+
+use std::time::Duration;
+
+use can::{Frame, RawMessage, GLOBAL_ADDRESS};
+use fast_packet::{self, is_fast_packet, FastPacketFragmenter};
+use transport::{self, TransportSender};
+
+/// How a message is to be sent.
+#[derive(Debug)]
+pub enum Transmission {
+    /// Frames which can be written to the bus one after the other. This is the case for single
+    /// frame messages, Fast Packets and transport protocol broadcasts.
+    Frames(Vec<Frame>),
+    /// A connection mode transfer to a single device. Write its
+    /// [request_to_send](../transport/struct.TransportSender.html#method.request_to_send) to the
+    /// bus and pass it the frames received from the device.
+    Connection(TransportSender),
+}
+
+/// Chooses how to send each message and splits it into frames.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use libnmea::can::{CanId, RawMessage};
+/// use libnmea::fragment::{Fragmenter, Transmission};
+///
+/// let message = RawMessage {
+///     id: CanId::new(6, 130816, 0x23, 0xff),
+///     data: vec![0x41, 0x9f, 1, 2, 3, 4, 5, 6, 7, 8],
+/// };
+/// let mut fragmenter = Fragmenter::new();
+///
+/// match fragmenter.fragment(message, Duration::from_millis(0)) {
+///     Some(Transmission::Frames(frames)) => assert_eq!(frames.len(), 2),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Fragmenter {
+    fast_packet: FastPacketFragmenter,
+}
+
+impl Fragmenter {
+    /// Creates a fragmenter with every Fast Packet sequence counter starting at 0.
+    pub fn new() -> Fragmenter {
+        Fragmenter::default()
+    }
+
+    /// Splits a message which is to be sent at time `now`. Returns `None` if the message is larger
+    /// than the [transport protocol](../transport/constant.MAX_SIZE.html) can carry.
+    pub fn fragment(&mut self, message: RawMessage, now: Duration) -> Option<Transmission> {
+        let size = message.data.len();
+        let fast_packet = is_fast_packet(message.id.pgn());
+
+        if size <= 8 && !fast_packet {
+            let frame = Frame::new(message.id, &message.data)?;
+            Some(Transmission::Frames(vec![frame]))
+        } else if size <= fast_packet::MAX_SIZE && fast_packet {
+            self.fast_packet
+                .fragment(&message)
+                .map(Transmission::Frames)
+        } else if message.id.destination() == GLOBAL_ADDRESS {
+            transport::broadcast(&message).map(Transmission::Frames)
+        } else {
+            TransportSender::new(message, now).map(Transmission::Connection)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use can::CanId;
+
+    fn fragment(pgn: u32, destination: u8, size: usize) -> Option<Transmission> {
+        let message = RawMessage {
+            id: CanId::new(6, pgn, 0x23, destination),
+            data: vec![0; size],
+        };
+        Fragmenter::new().fragment(message, Duration::from_millis(0))
+    }
+
+    fn frames(transmission: Option<Transmission>) -> Vec<Frame> {
+        match transmission {
+            Some(Transmission::Frames(frames)) => frames,
+            other => panic!("expected frames, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn single_frame() {
+        let frames = frames(fragment(127250, GLOBAL_ADDRESS, 8));
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].data().len(), 8);
+    }
+
+    #[test]
+    fn fast_packet() {
+        assert_eq!(frames(fragment(129029, GLOBAL_ADDRESS, 43)).len(), 7);
+        assert_eq!(frames(fragment(129029, GLOBAL_ADDRESS, 8)).len(), 2);
+    }
+
+    #[test]
+    fn too_large_for_fast_packet() {
+        let frames = frames(fragment(129029, GLOBAL_ADDRESS, fast_packet::MAX_SIZE + 1));
+
+        assert_eq!(frames[0].id.pgn(), transport::TP_CM);
+        assert_eq!(frames.len(), 1 + (fast_packet::MAX_SIZE + 1).div_ceil(7));
+    }
+
+    #[test]
+    fn addressed_transfer() {
+        match fragment(126464, 0x20, 9) {
+            Some(Transmission::Connection(sender)) => {
+                assert_eq!(sender.request_to_send().id.destination(), 0x20)
+            }
+            other => panic!("expected a connection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn too_large() {
+        assert!(fragment(126464, GLOBAL_ADDRESS, transport::MAX_SIZE + 1).is_none());
+        assert!(fragment(126464, 0x20, transport::MAX_SIZE + 1).is_none());
+    }
+}
@@ -0,0 +1,37 @@
+//! Hexadecimal helpers shared by the text log formats.
+
+use std::fmt::Write;
+
+/// Whether a string is made of nothing but hex digits. Unlike `from_str_radix`, a leading sign is
+/// not accepted.
+pub fn is_hex(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Parses a string of hex digit pairs, ignoring any spaces between them.
+pub fn decode(text: &str) -> Option<Vec<u8>> {
+    let digits: Vec<u8> = text.bytes().filter(|&b| b != b' ').collect();
+    if digits.len() % 2 != 0 || !digits.iter().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let pair = ::std::str::from_utf8(pair).ok()?;
+            u8::from_str_radix(pair, 16).ok()
+        })
+        .collect()
+}
+
+/// Formats bytes as upper case hex digit pairs, separated by `separator`.
+pub fn encode(data: &[u8], separator: &str) -> String {
+    let mut text = String::with_capacity(data.len() * (2 + separator.len()));
+    for (i, byte) in data.iter().enumerate() {
+        if i > 0 {
+            text.push_str(separator);
+        }
+        let _ = write!(text, "{:02X}", byte);
+    }
+    text
+}
@@ -1,4 +1,30 @@
-#[derive(Debug)]
+pub mod actisense;
+pub mod ais;
+pub mod bits;
+mod calendar;
+pub mod can;
+pub mod canboat;
+pub mod candump;
+pub mod decode;
+pub mod encapsulated;
+pub mod encode;
+pub mod fast_packet;
+pub mod fragment;
+mod hex;
+pub mod lookup;
+pub mod nmea0183;
+pub mod registry;
+#[cfg(target_os = "linux")]
+pub mod socketcan;
+pub mod transport;
+pub mod yacht_devices;
+
+pub use can::{CanId, Frame, RawMessage};
+pub use decode::{decode, decode_fields, DecodeError, FieldValue, Message, Value};
+pub use encode::{encode, encode_message, EncodeError};
+pub use lookup::Lookup;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PgnCategory {
     Mandatory,
     General,
@@ -13,7 +39,7 @@ pub enum PgnCategory {
     Other,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FieldType {
     Variable,
     NotUsed,
@@ -27,7 +53,7 @@ pub enum FieldType {
     WideString,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Unit {
     Volts,
     Hertz,
@@ -41,6 +67,19 @@ pub enum Unit {
     KilowattHours,
     VoltAmps,
     VoltAmpsReactive,
+    Meters,
+    MetersPerSecond,
+    Knots,
+    KilometersPerHour,
+    Amperes,
+    RevolutionsPerMinute,
+    Bars,
+    Kelvin,
+    Pascals,
+    Percent,
+    AmpereHours,
+    Liters,
+    LitersPerHour,
 
 }
 
@@ -83,30 +122,41 @@ pub struct Field {
     /// integer, etc. See [FieldType](enum.FieldType.html) Enum for possible values.
     pub field_type: Option<FieldType>,
     /// Bit offset from the beginning of the reassembled NMEA 2000 packet. Many PGNs will be a
-    /// single frame and will fit in a single 64-bit integer. However, some are much larger.
-    /// Reassembling a field which crosses a 64-bit boundary is complex.
+    /// single frame and will fit in a single 64-bit integer. However, some are much larger, and
+    /// their fields may cross a 64-bit boundary. See [BitReader](bits/struct.BitReader.html) for
+    /// extracting them.
     pub start: u16,
     /// How many bits long the field is.
     pub size: u16,
+    /// Whether an integer field holds a two's complement signed value. Affects which raw values
+    /// are reserved to mean that data is not available.
+    pub signed: bool,
     /// Most data is encoded as an integer value on the wire because the protocol is designed for
     /// small microcontrollers which may not have floating point hardware. For instance, a voltage
     /// measurement may be in 100ths of a volt, so a value of 1205 would be 12.05V. In this case,
     /// the multiplier value would be 0.01.
     pub multiplier: f64,
     /// Excess-K offset. See [Offset Binary](http://wikipedia.org/wiki/offset_binary).
-    pub offset: i64
+    pub offset: i64,
+    /// Names of the values of a `FieldType::Lookup` field. See [Lookup](lookup/struct.Lookup.html)
+    /// for the available tables.
+    pub lookup: Option<&'static Lookup>,
+    /// When a PGN has more than one definition, the value this field must hold for the definition
+    /// to apply. Most often used with the manufacturer code of proprietary PGNs.
+    pub match_value: Option<u64>,
+    /// Whether the field holds the number of times the repeating fields of the PGN repeat.
+    pub repeat_count: bool,
 }
 
 /// Constructs a list of `Pgn`s.
 ///
-/// Right now, returns a `Vec<Pgn>` structure, but a BTree or something with faster lookup times
-/// may be appropriate. The returned data structure is accessed every time a new PGN arrives on the
-/// wire.
+/// The list is built anew on every call, so it should not be used to look up PGNs as they arrive
+/// on the wire. Use the [registry](registry/index.html), which indexes this list once, instead.
 ///
 /// # Examples
 ///
 /// ```
-/// use libnmea::*
+/// use libnmea::*;
 ///
 /// let pgns = pgn_list();
 ///
@@ -125,6 +175,7 @@ pub fn pgn_list() -> Vec<Pgn> {
                 Field {
                     name: "Manufacturer Code",
                     field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::MANUFACTURER_CODE),
                     start:0,
                     size: 11,
                     ..Default::default()
@@ -133,6 +184,7 @@ pub fn pgn_list() -> Vec<Pgn> {
                 Field {
                     name: "Industry Code",
                     field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::INDUSTRY_CODE),
                     start: 13,
                     size: 3,
                     ..Default::default()
@@ -150,6 +202,7 @@ pub fn pgn_list() -> Vec<Pgn> {
                 Field {
                     name: "Control",
                     field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::ISO_ACK_CONTROL),
                     start: 0,
                     size: 8,
                     ..Default::default()
@@ -182,14 +235,4930 @@ pub fn pgn_list() -> Vec<Pgn> {
                 Field {
                     name: "PGN",
                     description: Some("Parameter group number of requested information"),
+                    start: 0,
+                    size: 24,
+                    field_type: Some(FieldType::Integer),
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "ISO Transport Protocol, Data Transfer",
+            category: PgnCategory::Mandatory,
+            pgn: 60160,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence number of the packet, starting at 1"),
+                    start: 0,
+                    size: 8,
+                    field_type: Some(FieldType::Integer),
+                    ..Default::default()
+                },
+                Field {
+                    name: "Data",
+                    start: 8,
+                    size: 56,
+                    field_type: Some(FieldType::Variable),
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "ISO Transport Protocol, Connection Management - Request To Send",
+            category: PgnCategory::Mandatory,
+            pgn: 60416,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Group Function Code",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TP_GROUP_FUNCTION),
+                    match_value: Some(16),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Message Size",
+                    description: Some("Total size of the message in bytes"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Packets",
+                    description: Some("Total number of packets in the message"),
+                    field_type: Some(FieldType::Integer),
+                    start: 24,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Packets Reply",
+                    description: Some("Maximum number of packets sent in reply to a CTS"),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "PGN",
+                    description: Some("Parameter group number of the message being transferred"),
+                    field_type: Some(FieldType::Integer),
+                    start: 40,
+                    size: 24,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "ISO Transport Protocol, Connection Management - Clear To Send",
+            category: PgnCategory::Mandatory,
+            pgn: 60416,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Group Function Code",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TP_GROUP_FUNCTION),
+                    match_value: Some(17),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Max Packets",
+                    description: Some("Number of packets which may be sent before the next CTS"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Next SID",
+                    description: Some("Sequence number of the next packet to send"),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 8,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+                Field {
+                    name: "PGN",
+                    description: Some("Parameter group number of the message being transferred"),
+                    field_type: Some(FieldType::Integer),
                     start: 40,
                     size: 24,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "ISO Transport Protocol, Connection Management - End Of Message",
+            category: PgnCategory::Mandatory,
+            pgn: 60416,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Group Function Code",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TP_GROUP_FUNCTION),
+                    match_value: Some(19),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Total Message Size",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Total Number of Packets Received",
+                    field_type: Some(FieldType::Integer),
+                    start: 24,
+                    size: 8,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+                Field {
+                    name: "PGN",
+                    description: Some("Parameter group number of the message being transferred"),
                     field_type: Some(FieldType::Integer),
+                    start: 40,
+                    size: 24,
                     ..Default::default()
                 },
             ],
         },
-    ];
-
-    pgn_list
+        Pgn {
+            name: "ISO Transport Protocol, Connection Management - Broadcast Announce",
+            category: PgnCategory::Mandatory,
+            pgn: 60416,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Group Function Code",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TP_GROUP_FUNCTION),
+                    match_value: Some(32),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Message Size",
+                    description: Some("Total size of the message in bytes"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Packets",
+                    description: Some("Total number of packets in the message"),
+                    field_type: Some(FieldType::Integer),
+                    start: 24,
+                    size: 8,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+                Field {
+                    name: "PGN",
+                    description: Some("Parameter group number of the message being transferred"),
+                    field_type: Some(FieldType::Integer),
+                    start: 40,
+                    size: 24,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "ISO Transport Protocol, Connection Management - Abort",
+            category: PgnCategory::Mandatory,
+            pgn: 60416,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Group Function Code",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TP_GROUP_FUNCTION),
+                    match_value: Some(255),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Reason",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                // 24 bits reserved
+                Field {
+                    name: "PGN",
+                    description: Some("Parameter group number of the message being transferred"),
+                    field_type: Some(FieldType::Integer),
+                    start: 40,
+                    size: 24,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "AIS Class A Position Report",
+            category: PgnCategory::Ais,
+            pgn: 129038,
+            is_known: true,
+            size: 28,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Message ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Repeat Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::REPEAT_INDICATOR),
+                    start: 6,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "User ID",
+                    description: Some("MMSI"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Longitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Latitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 72,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position Accuracy",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POSITION_ACCURACY),
+                    start: 104,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "RAIM",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::RAIM_FLAG),
+                    start: 105,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Time Stamp",
+                    description: Some("Second of the UTC minute the report was generated"),
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TIME_STAMP),
+                    start: 106,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "COG",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 112,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "SOG",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 128,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Communication State",
+                    description: Some("Information used by the TDMA slot allocation algorithm"),
+                    field_type: Some(FieldType::Integer),
+                    start: 144,
+                    size: 19,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AIS Transceiver information",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TRANSCEIVER),
+                    start: 163,
+                    size: 5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Heading",
+                    description: Some("True heading"),
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 168,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Rate of Turn",
+                    unit: Some(Unit::RadiansPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 184,
+                    size: 16,
+                    signed: true,
+                    multiplier: 3.125e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Nav Status",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::NAVIGATION_STATUS),
+                    start: 200,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Special Maneuver Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_SPECIAL_MANEUVER),
+                    start: 204,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 10 bits reserved
+                Field {
+                    name: "Sequence ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 216,
+                    size: 8,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "AIS Class B Position Report",
+            category: PgnCategory::Ais,
+            pgn: 129039,
+            is_known: true,
+            size: 27,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Message ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Repeat Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::REPEAT_INDICATOR),
+                    start: 6,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "User ID",
+                    description: Some("MMSI"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Longitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Latitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 72,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position Accuracy",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POSITION_ACCURACY),
+                    start: 104,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "RAIM",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::RAIM_FLAG),
+                    start: 105,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Time Stamp",
+                    description: Some("Second of the UTC minute the report was generated"),
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TIME_STAMP),
+                    start: 106,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "COG",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 112,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "SOG",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 128,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Communication State",
+                    description: Some("Information used by the TDMA slot allocation algorithm"),
+                    field_type: Some(FieldType::Integer),
+                    start: 144,
+                    size: 19,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AIS Transceiver information",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TRANSCEIVER),
+                    start: 163,
+                    size: 5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Heading",
+                    description: Some("True heading"),
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 168,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Regional Application",
+                    field_type: Some(FieldType::Integer),
+                    start: 184,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Regional Application B",
+                    field_type: Some(FieldType::Integer),
+                    start: 192,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Unit type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_UNIT_TYPE),
+                    start: 194,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Integrated Display",
+                    description: Some("Whether the unit can display messages 12 and 14"),
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 195,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "DSC",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 196,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Band",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_BAND),
+                    start: 197,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Can handle Msg 22",
+                    description: Some("Whether the unit accepts channel management"),
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 198,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AIS mode",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_ASSIGNED_MODE),
+                    start: 199,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AIS communication state",
+                    description: Some("0 for SOTDMA communication state, 1 for ITDMA"),
+                    field_type: Some(FieldType::Integer),
+                    start: 200,
+                    size: 1,
+                    ..Default::default()
+                },
+                // 15 bits reserved
+            ],
+        },
+        Pgn {
+            name: "AIS Class B Extended Position Report",
+            category: PgnCategory::Ais,
+            pgn: 129040,
+            is_known: true,
+            size: 53,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Message ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Repeat Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::REPEAT_INDICATOR),
+                    start: 6,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "User ID",
+                    description: Some("MMSI"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Longitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Latitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 72,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position Accuracy",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POSITION_ACCURACY),
+                    start: 104,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "RAIM",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::RAIM_FLAG),
+                    start: 105,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Time Stamp",
+                    description: Some("Second of the UTC minute the report was generated"),
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TIME_STAMP),
+                    start: 106,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "COG",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 112,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "SOG",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 128,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Regional Application",
+                    field_type: Some(FieldType::Integer),
+                    start: 144,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Regional Application B",
+                    field_type: Some(FieldType::Integer),
+                    start: 152,
+                    size: 4,
+                    ..Default::default()
+                },
+                // 4 bits reserved
+                Field {
+                    name: "Type of ship",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::SHIP_TYPE),
+                    start: 160,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "True Heading",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 168,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                // 4 bits reserved
+                Field {
+                    name: "GNSS type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POSITION_FIX_DEVICE),
+                    start: 188,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Length",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 192,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Beam",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 208,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position reference from Starboard",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 224,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position reference from Bow",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 240,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Name",
+                    field_type: Some(FieldType::AsciiString),
+                    start: 256,
+                    size: 160,
+                    ..Default::default()
+                },
+                Field {
+                    name: "DTE",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_DTE),
+                    start: 416,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AIS mode",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_ASSIGNED_MODE),
+                    start: 417,
+                    size: 1,
+                    ..Default::default()
+                },
+                // 1 bit reserved
+                Field {
+                    name: "AIS Transceiver information",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TRANSCEIVER),
+                    start: 419,
+                    size: 5,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "AIS Aids to Navigation (AtoN) Report",
+            category: PgnCategory::Ais,
+            pgn: 129041,
+            is_known: true,
+            size: 60,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Message ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Repeat Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::REPEAT_INDICATOR),
+                    start: 6,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "User ID",
+                    description: Some("MMSI"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Longitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Latitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 72,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position Accuracy",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POSITION_ACCURACY),
+                    start: 104,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "RAIM",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::RAIM_FLAG),
+                    start: 105,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Time Stamp",
+                    description: Some("Second of the UTC minute the report was generated"),
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TIME_STAMP),
+                    start: 106,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Length/Diameter",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 112,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Beam/Diameter",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 128,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position Reference from Starboard Edge",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 144,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position Reference from True North Facing Edge",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 160,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AtoN Type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::ATON_TYPE),
+                    start: 176,
+                    size: 5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Off Position Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 181,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Virtual AtoN Flag",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 182,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Assigned Mode Flag",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_ASSIGNED_MODE),
+                    start: 183,
+                    size: 1,
+                    ..Default::default()
+                },
+                // 1 bit reserved
+                Field {
+                    name: "Position Fixing Device Type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POSITION_FIX_DEVICE),
+                    start: 185,
+                    size: 4,
+                    ..Default::default()
+                },
+                // 3 bits reserved
+                Field {
+                    name: "AtoN Status",
+                    field_type: Some(FieldType::Integer),
+                    start: 192,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AIS Transceiver information",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TRANSCEIVER),
+                    start: 200,
+                    size: 5,
+                    ..Default::default()
+                },
+                // 3 bits reserved
+                Field {
+                    name: "AtoN Name",
+                    description: Some("Name, including the extension sent by AIS message 21"),
+                    field_type: Some(FieldType::PascalString),
+                    start: 208,
+                    size: 272,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "AIS Class A Static and Voyage Related Data",
+            category: PgnCategory::Ais,
+            pgn: 129794,
+            is_known: true,
+            size: 75,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Message ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Repeat Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::REPEAT_INDICATOR),
+                    start: 6,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "User ID",
+                    description: Some("MMSI"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "IMO number",
+                    field_type: Some(FieldType::Integer),
+                    start: 40,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Callsign",
+                    field_type: Some(FieldType::AsciiString),
+                    start: 72,
+                    size: 56,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Name",
+                    field_type: Some(FieldType::AsciiString),
+                    start: 128,
+                    size: 160,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Type of ship",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::SHIP_TYPE),
+                    start: 288,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Length",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 296,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Beam",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 312,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position reference from Starboard",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 328,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position reference from Bow",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 344,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "ETA Date",
+                    description: Some("Days since January 1, 1970"),
+                    field_type: Some(FieldType::Integer),
+                    start: 360,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "ETA Time",
+                    description: Some("Seconds since midnight"),
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Decimal),
+                    start: 376,
+                    size: 32,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Draft",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 408,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Destination",
+                    field_type: Some(FieldType::AsciiString),
+                    start: 424,
+                    size: 160,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AIS version indicator",
+                    field_type: Some(FieldType::Integer),
+                    start: 584,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "GNSS type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POSITION_FIX_DEVICE),
+                    start: 586,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "DTE",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_DTE),
+                    start: 590,
+                    size: 1,
+                    ..Default::default()
+                },
+                // 1 bit reserved
+                Field {
+                    name: "AIS Transceiver information",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TRANSCEIVER),
+                    start: 592,
+                    size: 5,
+                    ..Default::default()
+                },
+                // 3 bits reserved
+            ],
+        },
+        Pgn {
+            name: "AIS Class B \"CS\" Static Data Report, Part A",
+            category: PgnCategory::Ais,
+            pgn: 129809,
+            is_known: true,
+            size: 26,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Message ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Repeat Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::REPEAT_INDICATOR),
+                    start: 6,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "User ID",
+                    description: Some("MMSI"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Name",
+                    field_type: Some(FieldType::AsciiString),
+                    start: 40,
+                    size: 160,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AIS Transceiver information",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TRANSCEIVER),
+                    start: 200,
+                    size: 5,
+                    ..Default::default()
+                },
+                // 3 bits reserved
+            ],
+        },
+        Pgn {
+            name: "AIS Class B \"CS\" Static Data Report, Part B",
+            category: PgnCategory::Ais,
+            pgn: 129810,
+            is_known: true,
+            size: 34,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Message ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Repeat Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::REPEAT_INDICATOR),
+                    start: 6,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "User ID",
+                    description: Some("MMSI"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Type of ship",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::SHIP_TYPE),
+                    start: 40,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Vendor ID",
+                    field_type: Some(FieldType::AsciiString),
+                    start: 48,
+                    size: 56,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Callsign",
+                    field_type: Some(FieldType::AsciiString),
+                    start: 104,
+                    size: 56,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Length",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 160,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Beam",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 176,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position reference from Starboard",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 192,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position reference from Bow",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 208,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Mothership User ID",
+                    description: Some("MMSI of the mothership, sent by auxiliary craft"),
+                    field_type: Some(FieldType::Integer),
+                    start: 224,
+                    size: 32,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+                Field {
+                    name: "AIS Transceiver information",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::AIS_TRANSCEIVER),
+                    start: 264,
+                    size: 5,
+                    ..Default::default()
+                },
+                // 3 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Vessel Heading",
+            category: PgnCategory::Navigation,
+            pgn: 127250,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Heading",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Deviation",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Variation",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Reference",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::DIRECTION_REFERENCE),
+                    start: 56,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 6 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Rate of Turn",
+            category: PgnCategory::Navigation,
+            pgn: 127251,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Rate",
+                    unit: Some(Unit::RadiansPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 32,
+                    signed: true,
+                    multiplier: 3.125e-8,
+                    ..Default::default()
+                },
+                // 24 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Magnetic Variation",
+            category: PgnCategory::Navigation,
+            pgn: 127258,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Source",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::MAGNETIC_VARIATION_SOURCE),
+                    start: 8,
+                    size: 4,
+                    ..Default::default()
+                },
+                // 4 bits reserved
+                Field {
+                    name: "Age of service",
+                    description: Some("Days since January 1, 1970"),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Variation",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Speed",
+            category: PgnCategory::Navigation,
+            pgn: 128259,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Speed Water Referenced",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Speed Ground Referenced",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Speed Water Referenced Type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::WATER_REFERENCE),
+                    start: 40,
+                    size: 8,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Water Depth",
+            category: PgnCategory::Navigation,
+            pgn: 128267,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Depth",
+                    description: Some("Depth below the transducer"),
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 32,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Offset",
+                    description: Some("Transducer to waterline if positive, to keel if negative"),
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Range",
+                    description: Some("Maximum depth the transducer can measure"),
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 56,
+                    size: 8,
+                    multiplier: 10.0,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Position, Rapid Update",
+            category: PgnCategory::Navigation,
+            pgn: 129025,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Latitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 0,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Longitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "COG & SOG, Rapid Update",
+            category: PgnCategory::Navigation,
+            pgn: 129026,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "COG Reference",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::DIRECTION_REFERENCE),
+                    start: 8,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 6 bits reserved
+                Field {
+                    name: "COG",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 16,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "SOG",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "GNSS Position Data",
+            category: PgnCategory::Navigation,
+            pgn: 129029,
+            is_known: true,
+            size: 47,
+            repeating_fields: 3,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Date",
+                    description: Some("Days since January 1, 1970"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Time",
+                    description: Some("Seconds since midnight"),
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 32,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Latitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 56,
+                    size: 64,
+                    signed: true,
+                    multiplier: 1.0e-16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Longitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 120,
+                    size: 64,
+                    signed: true,
+                    multiplier: 1.0e-16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Altitude",
+                    description: Some("Altitude above the WGS84 ellipsoid"),
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 184,
+                    size: 64,
+                    signed: true,
+                    multiplier: 1.0e-6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "GNSS type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::GNSS_TYPE),
+                    start: 248,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Method",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::GNSS_METHOD),
+                    start: 252,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Integrity",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::GNSS_INTEGRITY),
+                    start: 256,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 6 bits reserved
+                Field {
+                    name: "Number of SVs",
+                    field_type: Some(FieldType::Integer),
+                    start: 264,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "HDOP",
+                    field_type: Some(FieldType::Decimal),
+                    start: 272,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "PDOP",
+                    field_type: Some(FieldType::Decimal),
+                    start: 288,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Geoidal Separation",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 304,
+                    size: 32,
+                    signed: true,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Reference Stations",
+                    field_type: Some(FieldType::Integer),
+                    start: 336,
+                    size: 8,
+                    repeat_count: true,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Reference Station Type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::GNSS_TYPE),
+                    start: 344,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Reference Station ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 348,
+                    size: 12,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Age of DGNSS Corrections",
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Decimal),
+                    start: 360,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Cross Track Error",
+            category: PgnCategory::Navigation,
+            pgn: 129283,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "XTE mode",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::XTE_MODE),
+                    start: 8,
+                    size: 4,
+                    ..Default::default()
+                },
+                // 2 bits reserved
+                Field {
+                    name: "Navigation Terminated",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 14,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "XTE",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 16,
+                    size: 32,
+                    signed: true,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Navigation Data",
+            category: PgnCategory::Navigation,
+            pgn: 129284,
+            is_known: true,
+            size: 34,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Distance to Waypoint",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 32,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Course/Bearing reference",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::DIRECTION_REFERENCE),
+                    start: 40,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Perpendicular Crossed",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 42,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Arrival Circle Entered",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 44,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Calculation Type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::BEARING_CALCULATION),
+                    start: 46,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "ETA Time",
+                    description: Some("Seconds since midnight"),
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Decimal),
+                    start: 48,
+                    size: 32,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "ETA Date",
+                    description: Some("Days since January 1, 1970"),
+                    field_type: Some(FieldType::Integer),
+                    start: 80,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Bearing, Origin to Destination Waypoint",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 96,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Bearing, Position to Destination Waypoint",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 112,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Origin Waypoint Number",
+                    field_type: Some(FieldType::Integer),
+                    start: 128,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Destination Waypoint Number",
+                    field_type: Some(FieldType::Integer),
+                    start: 160,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Destination Latitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 192,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Destination Longitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 224,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Waypoint Closing Velocity",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 256,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Navigation - Route/WP Information",
+            category: PgnCategory::Navigation,
+            pgn: 129285,
+            is_known: true,
+            size: 24,
+            repeating_fields: 4,
+            fields: vec![
+                Field {
+                    name: "Start RPS#",
+                    description: Some("Position in the route of the first waypoint listed"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "nItems",
+                    description: Some("Number of waypoints listed"),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    repeat_count: true,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Database ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Route ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 48,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Navigation direction in route",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::ROUTE_DIRECTION),
+                    start: 64,
+                    size: 3,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Supplementary Route/WP data available",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 67,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 3 bits reserved
+                Field {
+                    name: "Route Name",
+                    field_type: Some(FieldType::PascalString),
+                    start: 72,
+                    size: 16,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+                Field {
+                    name: "WP ID",
+                    field_type: Some(FieldType::Integer),
+                    start: 96,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "WP Name",
+                    field_type: Some(FieldType::PascalString),
+                    start: 112,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "WP Latitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 128,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "WP Longitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 160,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Wind Data",
+            category: PgnCategory::Navigation,
+            pgn: 130306,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Wind Speed",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Wind Angle",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Reference",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::WIND_REFERENCE),
+                    start: 40,
+                    size: 3,
+                    ..Default::default()
+                },
+                // 21 bits reserved
+            ],
+        },
+        Pgn {
+            name: "DC Detailed Status",
+            category: PgnCategory::Power,
+            pgn: 127506,
+            is_known: true,
+            size: 11,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "DC Type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::DC_SOURCE),
+                    start: 16,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "State of Charge",
+                    unit: Some(Unit::Percent),
+                    field_type: Some(FieldType::Integer),
+                    start: 24,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "State of Health",
+                    unit: Some(Unit::Percent),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Time Remaining",
+                    description: Some("Time until the battery is flat at the present load"),
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    multiplier: 60.0,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Ripple Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Decimal),
+                    start: 56,
+                    size: 16,
+                    multiplier: 0.001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Remaining Capacity",
+                    unit: Some(Unit::AmpereHours),
+                    field_type: Some(FieldType::Integer),
+                    start: 72,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Charger Status",
+            category: PgnCategory::Power,
+            pgn: 127507,
+            is_known: true,
+            size: 6,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Battery Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Operating State",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::CHARGER_STATE),
+                    start: 16,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Charge Mode",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::CHARGER_MODE),
+                    start: 20,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Enabled",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::OFF_ON),
+                    start: 24,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Equalization Pending",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::OFF_ON),
+                    start: 26,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 4 bits reserved
+                Field {
+                    name: "Equalization Time Remaining",
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Battery Status",
+            category: PgnCategory::Power,
+            pgn: 127508,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 56,
+                    size: 8,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Inverter Detailed Status",
+            category: PgnCategory::Power,
+            pgn: 127509,
+            is_known: true,
+            size: 4,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "DC Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Operating State",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::INVERTER_STATE),
+                    start: 24,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Inverter Enable",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::OFF_ON),
+                    start: 28,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 2 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Converter Status",
+            category: PgnCategory::Power,
+            pgn: 127750,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Connection Number",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Operating State",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::CONVERTER_STATE),
+                    start: 16,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Temperature State",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::GOOD_WARNING_ERROR),
+                    start: 24,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Overload State",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::GOOD_WARNING_ERROR),
+                    start: 26,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Low DC Voltage State",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::GOOD_WARNING_ERROR),
+                    start: 28,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Ripple State",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::GOOD_WARNING_ERROR),
+                    start: 30,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 32 bits reserved
+            ],
+        },
+        Pgn {
+            name: "DC Voltage/Current",
+            category: PgnCategory::Power,
+            pgn: 127751,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Connection Number",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "DC Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Decimal),
+                    start: 16,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "DC Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 24,
+                    signed: true,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Bus #1 Phase C Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65001,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Bus #1 Phase B Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65002,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Bus #1 Phase A Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65003,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Bus #1 Average Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65004,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Utility Total AC Energy",
+            category: PgnCategory::Power,
+            pgn: 65005,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Total Energy Export",
+                    unit: Some(Unit::KilowattHours),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Total Energy Import",
+                    unit: Some(Unit::KilowattHours),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Utility Phase C AC Reactive Power",
+            category: PgnCategory::Power,
+            pgn: 65006,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Reactive Power",
+                    unit: Some(Unit::VoltAmpsReactive),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor",
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 6.103515625e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor Lagging",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POWER_FACTOR),
+                    start: 48,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 14 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Utility Phase C AC Power",
+            category: PgnCategory::Power,
+            pgn: 65007,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Real Power",
+                    unit: Some(Unit::Watts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Apparent Power",
+                    unit: Some(Unit::VoltAmps),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Utility Phase C Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65008,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC RMS Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Integer),
+                    start: 48,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Utility Phase B AC Reactive Power",
+            category: PgnCategory::Power,
+            pgn: 65009,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Reactive Power",
+                    unit: Some(Unit::VoltAmpsReactive),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor",
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 6.103515625e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor Lagging",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POWER_FACTOR),
+                    start: 48,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 14 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Utility Phase B AC Power",
+            category: PgnCategory::Power,
+            pgn: 65010,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Real Power",
+                    unit: Some(Unit::Watts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Apparent Power",
+                    unit: Some(Unit::VoltAmps),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Utility Phase B Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65011,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC RMS Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Integer),
+                    start: 48,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Utility Phase A AC Reactive Power",
+            category: PgnCategory::Power,
+            pgn: 65012,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Reactive Power",
+                    unit: Some(Unit::VoltAmpsReactive),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor",
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 6.103515625e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor Lagging",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POWER_FACTOR),
+                    start: 48,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 14 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Utility Phase A AC Power",
+            category: PgnCategory::Power,
+            pgn: 65013,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Real Power",
+                    unit: Some(Unit::Watts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Apparent Power",
+                    unit: Some(Unit::VoltAmps),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Utility Phase A Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65014,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC RMS Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Integer),
+                    start: 48,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Utility Total AC Reactive Power",
+            category: PgnCategory::Power,
+            pgn: 65015,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Reactive Power",
+                    unit: Some(Unit::VoltAmpsReactive),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor",
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 6.103515625e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor Lagging",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POWER_FACTOR),
+                    start: 48,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 14 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Utility Total AC Power",
+            category: PgnCategory::Power,
+            pgn: 65016,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Real Power",
+                    unit: Some(Unit::Watts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Apparent Power",
+                    unit: Some(Unit::VoltAmps),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Utility Average Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65017,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC RMS Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Integer),
+                    start: 48,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Generator Total AC Energy",
+            category: PgnCategory::Power,
+            pgn: 65018,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Total Energy Export",
+                    unit: Some(Unit::KilowattHours),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Total Energy Import",
+                    unit: Some(Unit::KilowattHours),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Generator Phase C AC Reactive Power",
+            category: PgnCategory::Power,
+            pgn: 65019,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Reactive Power",
+                    unit: Some(Unit::VoltAmpsReactive),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor",
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 6.103515625e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor Lagging",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POWER_FACTOR),
+                    start: 48,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 14 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Generator Phase C AC Power",
+            category: PgnCategory::Power,
+            pgn: 65020,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Real Power",
+                    unit: Some(Unit::Watts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Apparent Power",
+                    unit: Some(Unit::VoltAmps),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Generator Phase C Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65021,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC RMS Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Integer),
+                    start: 48,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Generator Phase B AC Reactive Power",
+            category: PgnCategory::Power,
+            pgn: 65022,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Reactive Power",
+                    unit: Some(Unit::VoltAmpsReactive),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor",
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 6.103515625e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor Lagging",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POWER_FACTOR),
+                    start: 48,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 14 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Generator Phase B AC Power",
+            category: PgnCategory::Power,
+            pgn: 65023,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Real Power",
+                    unit: Some(Unit::Watts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Apparent Power",
+                    unit: Some(Unit::VoltAmps),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Generator Phase B Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65024,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC RMS Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Integer),
+                    start: 48,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Generator Phase A AC Reactive Power",
+            category: PgnCategory::Power,
+            pgn: 65025,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Reactive Power",
+                    unit: Some(Unit::VoltAmpsReactive),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor",
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 6.103515625e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor Lagging",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POWER_FACTOR),
+                    start: 48,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 14 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Generator Phase A AC Power",
+            category: PgnCategory::Power,
+            pgn: 65026,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Real Power",
+                    unit: Some(Unit::Watts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Apparent Power",
+                    unit: Some(Unit::VoltAmps),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Generator Phase A Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65027,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC RMS Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Integer),
+                    start: 48,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Generator Total AC Reactive Power",
+            category: PgnCategory::Power,
+            pgn: 65028,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Reactive Power",
+                    unit: Some(Unit::VoltAmpsReactive),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor",
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 6.103515625e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Factor Lagging",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::POWER_FACTOR),
+                    start: 48,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 14 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Generator Total AC Power",
+            category: PgnCategory::Power,
+            pgn: 65029,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Real Power",
+                    unit: Some(Unit::Watts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Apparent Power",
+                    unit: Some(Unit::VoltAmps),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 32,
+                    offset: -2000000000,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Generator Average Basic AC Quantities",
+            category: PgnCategory::Power,
+            pgn: 65030,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Line-Line AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Line-Neutral AC RMS Voltage",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Integer),
+                    start: 16,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC Frequency",
+                    unit: Some(Unit::Hertz),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.0078125,
+                    ..Default::default()
+                },
+                Field {
+                    name: "AC RMS Current",
+                    unit: Some(Unit::Amperes),
+                    field_type: Some(FieldType::Integer),
+                    start: 48,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Engine Parameters, Rapid Update",
+            category: PgnCategory::Propulsion,
+            pgn: 127488,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::ENGINE_INSTANCE),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Speed",
+                    unit: Some(Unit::RevolutionsPerMinute),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 16,
+                    multiplier: 0.25,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Boost Pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    multiplier: 100.0,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Tilt/Trim",
+                    unit: Some(Unit::Percent),
+                    field_type: Some(FieldType::Integer),
+                    start: 40,
+                    size: 8,
+                    signed: true,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Engine Parameters, Dynamic",
+            category: PgnCategory::Propulsion,
+            pgn: 127489,
+            is_known: true,
+            size: 26,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::ENGINE_INSTANCE),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Oil pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 16,
+                    multiplier: 100.0,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Oil temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Alternator Potential",
+                    unit: Some(Unit::Volts),
+                    field_type: Some(FieldType::Decimal),
+                    start: 56,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Fuel Rate",
+                    unit: Some(Unit::LitersPerHour),
+                    field_type: Some(FieldType::Decimal),
+                    start: 72,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Total Engine hours",
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Integer),
+                    start: 88,
+                    size: 32,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Coolant Pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 120,
+                    size: 16,
+                    multiplier: 100.0,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Fuel Pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 136,
+                    size: 16,
+                    multiplier: 1000.0,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+                Field {
+                    name: "Check Engine",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 160,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Over Temperature",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 161,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Low Oil Pressure",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 162,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Low Oil Level",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 163,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Low Fuel Pressure",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 164,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Low System Voltage",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 165,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Low Coolant Level",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 166,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Water Flow",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 167,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Water In Fuel",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 168,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Charge Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 169,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Preheat Indicator",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 170,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "High Boost Pressure",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 171,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Rev Limit Exceeded",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 172,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "EGR System",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 173,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Throttle Position Sensor",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 174,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Emergency Stop",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 175,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Warning Level 1",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 176,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Warning Level 2",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 177,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Power Reduction",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 178,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Maintenance Needed",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 179,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Engine Comm Error",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 180,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Sub or Secondary Throttle",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 181,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Neutral Start Protect",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 182,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Engine Shutting Down",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 183,
+                    size: 1,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+                Field {
+                    name: "Engine Load",
+                    unit: Some(Unit::Percent),
+                    field_type: Some(FieldType::Integer),
+                    start: 192,
+                    size: 8,
+                    signed: true,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Engine Torque",
+                    unit: Some(Unit::Percent),
+                    field_type: Some(FieldType::Integer),
+                    start: 200,
+                    size: 8,
+                    signed: true,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Transmission Parameters, Dynamic",
+            category: PgnCategory::Propulsion,
+            pgn: 127493,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::ENGINE_INSTANCE),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Transmission Gear",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::GEAR_STATUS),
+                    start: 8,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 6 bits reserved
+                Field {
+                    name: "Oil pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 16,
+                    size: 16,
+                    multiplier: 100.0,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Oil temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Check Temperature",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 48,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Over Temperature",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 49,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Low Oil Pressure",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 50,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Low Oil Level",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 51,
+                    size: 1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Sail Drive",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 52,
+                    size: 1,
+                    ..Default::default()
+                },
+                // 11 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Trip Parameters, Vessel",
+            category: PgnCategory::Propulsion,
+            pgn: 127496,
+            is_known: true,
+            size: 14,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Time to Empty",
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Decimal),
+                    start: 0,
+                    size: 32,
+                    multiplier: 0.001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Distance to Empty",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 32,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Estimated Fuel Remaining",
+                    unit: Some(Unit::Liters),
+                    field_type: Some(FieldType::Integer),
+                    start: 64,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Trip Run Time",
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Decimal),
+                    start: 80,
+                    size: 32,
+                    multiplier: 0.001,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Trip Parameters, Engine",
+            category: PgnCategory::Propulsion,
+            pgn: 127497,
+            is_known: true,
+            size: 9,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::ENGINE_INSTANCE),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Trip Fuel Used",
+                    unit: Some(Unit::Liters),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Fuel Rate, Average",
+                    unit: Some(Unit::LitersPerHour),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Fuel Rate, Economy",
+                    unit: Some(Unit::LitersPerHour),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Instantaneous Fuel Economy",
+                    unit: Some(Unit::LitersPerHour),
+                    field_type: Some(FieldType::Decimal),
+                    start: 56,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Engine Parameters, Static",
+            category: PgnCategory::Propulsion,
+            pgn: 127498,
+            is_known: true,
+            size: 52,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::ENGINE_INSTANCE),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Rated Engine Speed",
+                    unit: Some(Unit::RevolutionsPerMinute),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 16,
+                    multiplier: 0.25,
+                    ..Default::default()
+                },
+                Field {
+                    name: "VIN",
+                    field_type: Some(FieldType::AsciiString),
+                    start: 24,
+                    size: 136,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Software ID",
+                    field_type: Some(FieldType::AsciiString),
+                    start: 160,
+                    size: 256,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Fluid Level",
+            category: PgnCategory::Propulsion,
+            pgn: 127505,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Type",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TANK_TYPE),
+                    start: 4,
+                    size: 4,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Level",
+                    unit: Some(Unit::Percent),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.004,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Capacity",
+                    unit: Some(Unit::Liters),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 32,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Heading/Track control",
+            category: PgnCategory::Steering,
+            pgn: 127237,
+            is_known: true,
+            size: 21,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Rudder Limit Exceeded",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 0,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Off-Heading Limit Exceeded",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 2,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Off-Track Limit Exceeded",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 4,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Override",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::YES_NO),
+                    start: 6,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Steering Mode",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::STEERING_MODE),
+                    start: 8,
+                    size: 3,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Turn Mode",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TURN_MODE),
+                    start: 11,
+                    size: 3,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Heading Reference",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::DIRECTION_REFERENCE),
+                    start: 14,
+                    size: 2,
+                    ..Default::default()
+                },
+                // 5 bits reserved
+                Field {
+                    name: "Commanded Rudder Direction",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::DIRECTION_RUDDER),
+                    start: 21,
+                    size: 3,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Commanded Rudder Angle",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Heading-To-Steer (Course)",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Track",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 56,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Rudder Limit",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 72,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Off-Heading Limit",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 88,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Radius of Turn Order",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Integer),
+                    start: 104,
+                    size: 16,
+                    signed: true,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Rate of Turn Order",
+                    unit: Some(Unit::RadiansPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 120,
+                    size: 16,
+                    signed: true,
+                    multiplier: 3.125e-5,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Off-Track Limit",
+                    unit: Some(Unit::Meters),
+                    field_type: Some(FieldType::Integer),
+                    start: 136,
+                    size: 16,
+                    signed: true,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Vessel Heading",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 152,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Rudder",
+            category: PgnCategory::Steering,
+            pgn: 127245,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Direction Order",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::DIRECTION_RUDDER),
+                    start: 8,
+                    size: 3,
+                    ..Default::default()
+                },
+                // 5 bits reserved
+                Field {
+                    name: "Angle Order",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 16,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Position",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                // 16 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Environmental Parameters (obsolete)",
+            category: PgnCategory::Environmental,
+            pgn: 130310,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Water Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 8,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Outside Ambient Air Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Atmospheric Pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    multiplier: 100.0,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Environmental Parameters",
+            category: PgnCategory::Environmental,
+            pgn: 130311,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Temperature Source",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TEMPERATURE_SOURCE),
+                    start: 8,
+                    size: 6,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Humidity Source",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::HUMIDITY_SOURCE),
+                    start: 14,
+                    size: 2,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 16,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Humidity",
+                    unit: Some(Unit::Percent),
+                    field_type: Some(FieldType::Decimal),
+                    start: 32,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.004,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Atmospheric Pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 48,
+                    size: 16,
+                    multiplier: 100.0,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Temperature",
+            category: PgnCategory::Environmental,
+            pgn: 130312,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Source",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TEMPERATURE_SOURCE),
+                    start: 16,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Actual Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Set Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Humidity",
+            category: PgnCategory::Environmental,
+            pgn: 130313,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Source",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::HUMIDITY_SOURCE),
+                    start: 16,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Actual Humidity",
+                    unit: Some(Unit::Percent),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.004,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Set Humidity",
+                    unit: Some(Unit::Percent),
+                    field_type: Some(FieldType::Decimal),
+                    start: 40,
+                    size: 16,
+                    signed: true,
+                    multiplier: 0.004,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Actual Pressure",
+            category: PgnCategory::Environmental,
+            pgn: 130314,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Source",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::PRESSURE_SOURCE),
+                    start: 16,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 32,
+                    signed: true,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Set Pressure",
+            category: PgnCategory::Environmental,
+            pgn: 130315,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Source",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::PRESSURE_SOURCE),
+                    start: 16,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 32,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+                // 8 bits reserved
+            ],
+        },
+        Pgn {
+            name: "Temperature Extended Range",
+            category: PgnCategory::Environmental,
+            pgn: 130316,
+            is_known: true,
+            size: 8,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "SID",
+                    description: Some("Sequence ID, shared by messages about the same moment"),
+                    field_type: Some(FieldType::Integer),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Instance",
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Source",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::TEMPERATURE_SOURCE),
+                    start: 16,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 24,
+                    multiplier: 0.001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Set Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 48,
+                    size: 16,
+                    multiplier: 0.1,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "Meteorological Station Data",
+            category: PgnCategory::Environmental,
+            pgn: 130323,
+            is_known: true,
+            size: 30,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Mode",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::XTE_MODE),
+                    start: 0,
+                    size: 4,
+                    ..Default::default()
+                },
+                // 4 bits reserved
+                Field {
+                    name: "Measurement Date",
+                    description: Some("Days since January 1, 1970"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Measurement Time",
+                    description: Some("Seconds since midnight"),
+                    unit: Some(Unit::Seconds),
+                    field_type: Some(FieldType::Decimal),
+                    start: 24,
+                    size: 32,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Station Latitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 56,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Station Longitude",
+                    unit: Some(Unit::Degrees),
+                    field_type: Some(FieldType::Decimal),
+                    start: 88,
+                    size: 32,
+                    signed: true,
+                    multiplier: 1.0e-7,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Wind Speed",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 120,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Wind Direction",
+                    unit: Some(Unit::Radians),
+                    field_type: Some(FieldType::Decimal),
+                    start: 136,
+                    size: 16,
+                    multiplier: 0.0001,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Wind Reference",
+                    field_type: Some(FieldType::Lookup),
+                    lookup: Some(&lookup::WIND_REFERENCE),
+                    start: 152,
+                    size: 3,
+                    ..Default::default()
+                },
+                // 5 bits reserved
+                Field {
+                    name: "Wind Gusts",
+                    unit: Some(Unit::MetersPerSecond),
+                    field_type: Some(FieldType::Decimal),
+                    start: 160,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Atmospheric Pressure",
+                    unit: Some(Unit::Pascals),
+                    field_type: Some(FieldType::Decimal),
+                    start: 176,
+                    size: 16,
+                    multiplier: 100.0,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Ambient Temperature",
+                    unit: Some(Unit::Kelvin),
+                    field_type: Some(FieldType::Decimal),
+                    start: 192,
+                    size: 16,
+                    multiplier: 0.01,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Station ID",
+                    field_type: Some(FieldType::PascalString),
+                    start: 208,
+                    size: 16,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Station Name",
+                    field_type: Some(FieldType::PascalString),
+                    start: 224,
+                    size: 16,
+                    ..Default::default()
+                },
+            ],
+        },
+        Pgn {
+            name: "NMEA - Command group function",
+            category: PgnCategory::General,
+            pgn: 126208,
+            is_known: true,
+            size: 6,
+            repeating_fields: 0,
+            fields: vec![
+                Field {
+                    name: "Function Code",
+                    field_type: Some(FieldType::Integer),
+                    match_value: Some(1),
+                    start: 0,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "PGN",
+                    description: Some("PGN to set fields of"),
+                    field_type: Some(FieldType::Integer),
+                    start: 8,
+                    size: 24,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Priority",
+                    description: Some("New priority of the PGN, or 8 to keep it"),
+                    field_type: Some(FieldType::Integer),
+                    start: 32,
+                    size: 4,
+                    ..Default::default()
+                },
+                // 4 bits reserved
+                Field {
+                    name: "Number of Parameters",
+                    field_type: Some(FieldType::Integer),
+                    start: 40,
+                    size: 8,
+                    ..Default::default()
+                },
+                Field {
+                    name: "Parameters",
+                    description: Some("Each field number followed by its new value"),
+                    field_type: Some(FieldType::Variable),
+                    start: 48,
+                    size: 0,
+                    ..Default::default()
+                },
+            ],
+        },
+    ];
+
+    pgn_list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Angles are in radians and speeds in metres per second.
+    #[test]
+    fn cog_sog_rapid_update() {
+        let data = [0x00, 0xfc, 0x10, 0x27, 0x02, 0x02, 0xff, 0xff];
+        let message = decode(CanId::new(2, 129026, 0x01, 0xff), &data).unwrap();
+
+        assert_eq!(message.get("COG Reference"), Some(&Value::Lookup(0, Some("True"))));
+        assert_eq!(message.get("COG"), Some(&Value::Decimal(1.0)));
+        assert_eq!(message.get("SOG"), Some(&Value::Decimal(5.14)));
+    }
+
+    /// Status bits are decoded into a field for each flag.
+    #[test]
+    fn engine_status_flags() {
+        // Engine Parameters, Dynamic with Check Engine, Low Oil Pressure, Warning Level 1 and
+        // Engine Shutting Down raised.
+        let data = [
+            0x00, 0xe8, 0x03, 0x9b, 0x0b, 0x9b, 0x73, 0xb0, 0x04, 0x10, 0x00, 0x10, 0x0e, 0x00, 0x00,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x05, 0x00, 0x81, 0x00, 0x28, 0x1e,
+        ];
+        let message = decode(CanId::new(2, 127489, 0x01, 0xff), &data).unwrap();
+
+        assert_eq!(message.get("Check Engine"), Some(&Value::Lookup(1, Some("Yes"))));
+        assert_eq!(message.get("Over Temperature"), Some(&Value::Lookup(0, Some("No"))));
+        assert_eq!(message.get("Low Oil Pressure"), Some(&Value::Lookup(1, Some("Yes"))));
+        assert_eq!(message.get("Emergency Stop"), Some(&Value::Lookup(0, Some("No"))));
+        assert_eq!(message.get("Warning Level 1"), Some(&Value::Lookup(1, Some("Yes"))));
+        assert_eq!(message.get("Engine Shutting Down"), Some(&Value::Lookup(1, Some("Yes"))));
+        assert_eq!(message.get("Fuel Rate"), Some(&Value::Decimal(1.6)));
+        assert_eq!(message.get("Engine Load"), Some(&Value::Integer(40)));
+    }
+
+    /// Rudder angles are positive to starboard.
+    #[test]
+    fn rudder() {
+        let data = [0x00, 0xf8, 0xff, 0x7f, 0x30, 0xf8, 0xff, 0xff];
+        let rudder = decode(CanId::new(2, 127245, 0x01, 0xff), &data).unwrap();
+
+        assert_eq!(rudder.get("Direction Order"), Some(&Value::Lookup(0, Some("No Order"))));
+        assert_eq!(rudder.get("Angle Order"), Some(&Value::NotAvailable));
+        assert_eq!(rudder.get("Position"), Some(&Value::Decimal(-0.2)));
+    }
+
+    /// Temperatures are in kelvin and pressures in pascals, as they are sent.
+    #[test]
+    fn temperature_and_pressure() {
+        let data = [0x00, 0x01, 0x00, 0x30, 0x75, 0xff, 0xff, 0xff];
+        let temperature = decode(CanId::new(5, 130312, 0x01, 0xff), &data).unwrap();
+
+        assert_eq!(temperature.get("Source"), Some(&Value::Lookup(0, Some("Sea Temperature"))));
+        assert_eq!(temperature.get("Actual Temperature"), Some(&Value::Decimal(300.0)));
+        assert_eq!(temperature.get("Set Temperature"), Some(&Value::NotAvailable));
+        assert_eq!(temperature.fields[3].unit, Some(Unit::Kelvin));
+
+        let data = [0x00, 0x00, 0x00, 0x02, 0x76, 0x0f, 0x00, 0xff];
+        let pressure = decode(CanId::new(5, 130314, 0x01, 0xff), &data).unwrap();
+
+        assert_eq!(pressure.get("Source"), Some(&Value::Lookup(0, Some("Atmospheric"))));
+        assert_eq!(pressure.get("Pressure"), Some(&Value::Decimal(101325.0)));
+        assert_eq!(pressure.fields[3].unit, Some(Unit::Pascals));
+    }
 }
@@ -0,0 +1,800 @@
+//! Enumerations referenced by [Lookup](../enum.FieldType.html) fields.
+
+/// A table of the names given to the values of an enumerated field.
+#[derive(Debug)]
+pub struct Lookup {
+    /// Name of the enumeration. Primarily of use for documentation and debugging.
+    pub name: &'static str,
+    /// Values and their names, sorted by value.
+    pub values: &'static [(u32, &'static str)],
+}
+
+impl Lookup {
+    /// Returns the name of a raw value, if it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::lookup::INDUSTRY_CODE;
+    ///
+    /// assert_eq!(INDUSTRY_CODE.name_of(4), Some("Marine"));
+    /// assert_eq!(INDUSTRY_CODE.value_of("Marine"), Some(4));
+    /// ```
+    pub fn name_of(&self, value: u32) -> Option<&'static str> {
+        self.values
+            .binary_search_by_key(&value, |&(v, _)| v)
+            .ok()
+            .map(|i| self.values[i].1)
+    }
+
+    /// Returns the raw value with the given name. Names are compared ignoring case, and are
+    /// distinct within a table, so a name has only one value.
+    pub fn value_of(&self, name: &str) -> Option<u32> {
+        self.values
+            .iter()
+            .find(|&&(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|&(v, _)| v)
+    }
+}
+
+/// Manufacturer codes assigned by NMEA.
+pub static MANUFACTURER_CODE: Lookup = Lookup {
+    name: "Manufacturer Code",
+    values: &[
+        (69, "ARKS Enterprises, Inc."),
+        (78, "FW Murphy/Enovation Controls"),
+        (80, "Twin Disc"),
+        (85, "Kohler Power Systems"),
+        (88, "Hemisphere GPS Inc"),
+        (116, "BEP Marine"),
+        (135, "Airmar"),
+        (137, "Maretron"),
+        (140, "Lowrance"),
+        (144, "Mercury Marine"),
+        (147, "Nautibus Electronic GmbH"),
+        (148, "Blue Water Data"),
+        (154, "Westerbeke"),
+        (161, "Offshore Systems (UK) Ltd."),
+        (163, "Evinrude/BRP"),
+        (165, "CPAC Systems AB"),
+        (168, "Xantrex Technology Inc."),
+        (172, "Yanmar Marine"),
+        (174, "Volvo Penta"),
+        (175, "Honda Marine"),
+        (176, "Carling Technologies Inc. (Moritz Aerospace)"),
+        (185, "Beede Instruments"),
+        (192, "Floscan Instrumentation Inc."),
+        (193, "Nobletec"),
+        (198, "Mystic Valley Communications"),
+        (199, "Actia"),
+        (201, "Disenos Y Technologia"),
+        (211, "Digital Switching Systems"),
+        (215, "Xintex/Fireboy"),
+        (224, "EMMI Network S.L."),
+        (228, "ZF"),
+        (229, "Garmin"),
+        (233, "Yacht Monitoring Solutions"),
+        (235, "Sailormade Marine Telemetry/Tetra Technology LTD"),
+        (243, "Eride"),
+        (257, "Honda Motor Company LTD"),
+        (272, "Groco"),
+        (273, "Actisense"),
+        (274, "Amphenol LTW Technology"),
+        (275, "Navico"),
+        (283, "Hamilton Jet"),
+        (285, "Sea Recovery"),
+        (286, "Coelmo SRL Italy"),
+        (295, "BEP Marine (295)"),
+        (304, "Empir Bus"),
+        (305, "NovAtel"),
+        (306, "Sleipner Motor AS"),
+        (307, "MBW Technologies"),
+        (311, "Fischer Panda"),
+        (315, "ICOM"),
+        (328, "Qwerty"),
+        (329, "Dief"),
+        (341, "Boening Automationstechnologie GmbH & Co. KG"),
+        (345, "Korean Maritime University"),
+        (351, "Thrane and Thrane"),
+        (355, "Mastervolt"),
+        (356, "Fischer Panda Generators"),
+        (358, "Victron Energy"),
+        (370, "Rolls Royce Marine"),
+        (373, "Electronic Design"),
+        (374, "Northern Lights"),
+        (378, "Glendinning"),
+        (381, "B & G"),
+        (384, "Rose Point Navigation Systems"),
+        (385, "Johnson Outdoors Marine Electronics Inc Geonav"),
+        (394, "Capi 2"),
+        (396, "Beyond Measure"),
+        (400, "Livorsi Marine"),
+        (404, "ComNav"),
+        (409, "Chetco"),
+        (419, "Fusion Electronics"),
+        (421, "Standard Horizon"),
+        (422, "True Heading AB"),
+        (426, "Egersund Marine Electronics AS"),
+        (427, "em-trak Marine Electronics"),
+        (431, "Tohatsu Co, JP"),
+        (437, "Digital Yacht"),
+        (438, "Comar Systems Limited"),
+        (440, "Cummins"),
+        (443, "VDO (aka Continental-Corporation)"),
+        (451, "Parker Hannifin aka Village Marine Tech"),
+        (459, "Alltek Marine Electronics Corp"),
+        (460, "SAN GIORGIO S.E.I.N"),
+        (466, "Veethree Electronics & Marine"),
+        (467, "Humminbird Marine Electronics"),
+        (470, "SI-TEX Marine Electronics"),
+        (471, "Sea Cross Marine AB"),
+        (475, "GME aka Standard Communications Pty LTD"),
+        (476, "Humminbird Marine Electronics (476)"),
+        (478, "Ocean Sat BV"),
+        (481, "Chetco Digitial Instruments"),
+        (493, "Watcheye"),
+        (499, "Lcj Capteurs"),
+        (502, "Attwood Marine"),
+        (503, "Naviop S.R.L."),
+        (504, "Vesper Marine Ltd"),
+        (510, "Marinesoft Co. LTD"),
+        (517, "NoLand Engineering"),
+        (518, "Transas USA"),
+        (529, "National Instruments Korea"),
+        (532, "Onwa Marine"),
+        (573, "McMurdo Group aka Orolia LTD"),
+        (578, "Advansea"),
+        (579, "KVH"),
+        (580, "San Jose Technology"),
+        (583, "Yacht Control"),
+        (586, "Suzuki Motor Corporation"),
+        (591, "US Coast Guard"),
+        (595, "Ship Module aka Customware"),
+        (600, "Aquatic AV"),
+        (605, "Aventics GmbH"),
+        (606, "Intellian"),
+        (612, "SamwonIT"),
+        (614, "Arlt Tecnologies"),
+        (637, "Bavaria Yacts"),
+        (641, "Diverse Yacht Services"),
+        (644, "Wema U.S.A dba KUS"),
+        (645, "Garmin (645)"),
+        (658, "Shenzhen Jiuzhou Himunication"),
+        (688, "Rockford Corp"),
+        (704, "JL Audio"),
+        (715, "Autonnic"),
+        (717, "Yacht Devices"),
+        (734, "REAP Systems"),
+        (735, "Au Electronics Group"),
+        (739, "LxNav"),
+        (743, "DaeMyung"),
+        (744, "Woosung"),
+        (773, "Clarion US"),
+        (776, "HMI Systems"),
+        (777, "Ocean Signal"),
+        (778, "Seekeeper"),
+        (781, "Poly Planar"),
+        (785, "Fischer Panda DE"),
+        (795, "Broyda Industries"),
+        (796, "Canadian Automotive"),
+        (797, "Tides Marine"),
+        (798, "Lumishore"),
+        (799, "Still Water Designs and Audio"),
+        (802, "BJ Technologies (Beneteau)"),
+        (803, "Gill Sensors"),
+        (811, "Blue Water Desalination"),
+        (815, "FLIR"),
+        (824, "Undheim Systems"),
+        (838, "TeamSurv"),
+        (844, "Fell Marine"),
+        (847, "Oceanvolt"),
+        (862, "Prospec"),
+        (868, "Data Panel Corp"),
+        (890, "L3 Technologies"),
+        (894, "Rhodan Marine Systems"),
+        (896, "Nexfour Solutions"),
+        (905, "ASA Electronics"),
+        (909, "Marines Co (South Korea)"),
+        (911, "Nautic-on"),
+        (930, "Ecotronix"),
+        (962, "Timbolier Industries"),
+        (963, "TJC Micro"),
+        (968, "Cox Powertrain"),
+        (969, "Blue Seas"),
+        (1850, "Teleflex Marine (SeaStar Solutions)"),
+        (1851, "Raymarine"),
+        (1852, "Navionics"),
+        (1853, "Japan Radio Co"),
+        (1854, "Northstar Technologies"),
+        (1855, "Furuno"),
+        (1856, "Trimble"),
+        (1857, "Simrad"),
+        (1858, "Litton"),
+        (1859, "Kvasar AB"),
+        (1860, "MMP"),
+        (1861, "Vector Cantech"),
+        (1862, "Yamaha Marine"),
+        (1863, "Faria Instruments"),
+    ],
+};
+
+/// Industry groups from ISO 11783. NMEA 2000 devices belong to the marine industry group.
+pub static INDUSTRY_CODE: Lookup = Lookup {
+    name: "Industry Code",
+    values: &[
+        (0, "Global"),
+        (1, "Highway"),
+        (2, "Agriculture"),
+        (3, "Construction"),
+        (4, "Marine"),
+        (5, "Industrial"),
+    ],
+};
+
+/// Control byte of an ISO Acknowledgement.
+pub static ISO_ACK_CONTROL: Lookup = Lookup {
+    name: "ISO Acknowledgement Control",
+    values: &[
+        (0, "ACK"),
+        (1, "NAK"),
+        (2, "Access Denied"),
+        (3, "Address Busy"),
+    ],
+};
+
+/// Group function code of an ISO transport protocol connection management message.
+pub static TP_GROUP_FUNCTION: Lookup = Lookup {
+    name: "Transport Protocol Group Function",
+    values: &[
+        (16, "Request to Send"),
+        (17, "Clear to Send"),
+        (19, "End of Message Acknowledgement"),
+        (32, "Broadcast Announce"),
+        (255, "Connection Abort"),
+    ],
+};
+
+/// Navigational status of a vessel, as sent by AIS.
+pub static NAVIGATION_STATUS: Lookup = Lookup {
+    name: "Navigation Status",
+    values: &[
+        (0, "Under way using engine"),
+        (1, "At anchor"),
+        (2, "Not under command"),
+        (3, "Restricted maneuverability"),
+        (4, "Constrained by her draught"),
+        (5, "Moored"),
+        (6, "Aground"),
+        (7, "Engaged in fishing"),
+        (8, "Under way sailing"),
+        (9, "Hazardous material, high speed"),
+        (10, "Hazardous material, wing in ground"),
+        (11, "Power-driven vessel towing astern"),
+        (12, "Power-driven vessel pushing ahead or towing alongside"),
+        (14, "AIS-SART"),
+        (15, "Undefined"),
+    ],
+};
+
+/// Type of ship and cargo, as sent by AIS. The first digit of most values is the kind of ship,
+/// and the second the category of hazardous cargo it carries.
+pub static SHIP_TYPE: Lookup = Lookup {
+    name: "Ship Type",
+    values: &[
+        (0, "Unavailable"),
+        (20, "Wing In Ground"),
+        (21, "Wing In Ground, hazard category X"),
+        (22, "Wing In Ground, hazard category Y"),
+        (23, "Wing In Ground, hazard category Z"),
+        (24, "Wing In Ground, hazard category OS"),
+        (29, "Wing In Ground, no additional information"),
+        (30, "Fishing"),
+        (31, "Towing"),
+        (32, "Towing, exceeds 200m or wider than 25m"),
+        (33, "Engaged in dredging or underwater operations"),
+        (34, "Engaged in diving operations"),
+        (35, "Engaged in military operations"),
+        (36, "Sailing"),
+        (37, "Pleasure"),
+        (40, "High speed craft"),
+        (41, "High speed craft, hazard category X"),
+        (42, "High speed craft, hazard category Y"),
+        (43, "High speed craft, hazard category Z"),
+        (44, "High speed craft, hazard category OS"),
+        (49, "High speed craft, no additional information"),
+        (50, "Pilot vessel"),
+        (51, "SAR"),
+        (52, "Tug"),
+        (53, "Port tender"),
+        (54, "Anti-pollution"),
+        (55, "Law enforcement"),
+        (56, "Spare"),
+        (57, "Spare #2"),
+        (58, "Medical"),
+        (59, "RR Resolution No.18"),
+        (60, "Passenger ship"),
+        (61, "Passenger ship, hazard category X"),
+        (62, "Passenger ship, hazard category Y"),
+        (63, "Passenger ship, hazard category Z"),
+        (64, "Passenger ship, hazard category OS"),
+        (69, "Passenger ship, no additional information"),
+        (70, "Cargo ship"),
+        (71, "Cargo ship, hazard category X"),
+        (72, "Cargo ship, hazard category Y"),
+        (73, "Cargo ship, hazard category Z"),
+        (74, "Cargo ship, hazard category OS"),
+        (79, "Cargo ship, no additional information"),
+        (80, "Tanker"),
+        (81, "Tanker, hazard category X"),
+        (82, "Tanker, hazard category Y"),
+        (83, "Tanker, hazard category Z"),
+        (84, "Tanker, hazard category OS"),
+        (89, "Tanker, no additional information"),
+        (90, "Other"),
+        (91, "Other, hazard category X"),
+        (92, "Other, hazard category Y"),
+        (93, "Other, hazard category Z"),
+        (94, "Other, hazard category OS"),
+        (99, "Other, no additional information"),
+    ],
+};
+
+/// Type of electronic position fixing device, as sent by AIS.
+pub static POSITION_FIX_DEVICE: Lookup = Lookup {
+    name: "Position Fix Device",
+    values: &[
+        (0, "Undefined"),
+        (1, "GPS"),
+        (2, "GLONASS"),
+        (3, "GPS+GLONASS"),
+        (4, "Loran-C"),
+        (5, "Chayka"),
+        (6, "Integrated navigation system"),
+        (7, "Surveyed"),
+        (8, "Galileo"),
+        (15, "Internal GNSS"),
+    ],
+};
+
+/// Type of aid to navigation, as sent by AIS.
+pub static ATON_TYPE: Lookup = Lookup {
+    name: "AtoN Type",
+    values: &[
+        (0, "Default: Type of AtoN not specified"),
+        (1, "Reference point"),
+        (2, "RACON"),
+        (3, "Fixed structure off-shore"),
+        (4, "Reserved for future use"),
+        (5, "Fixed light: without sectors"),
+        (6, "Fixed light: with sectors"),
+        (7, "Fixed leading light front"),
+        (8, "Fixed leading light rear"),
+        (9, "Fixed beacon: cardinal N"),
+        (10, "Fixed beacon: cardinal E"),
+        (11, "Fixed beacon: cardinal S"),
+        (12, "Fixed beacon: cardinal W"),
+        (13, "Fixed beacon: port hand"),
+        (14, "Fixed beacon: starboard hand"),
+        (15, "Fixed beacon: preferred channel port hand"),
+        (16, "Fixed beacon: preferred channel starboard hand"),
+        (17, "Fixed beacon: isolated danger"),
+        (18, "Fixed beacon: safe water"),
+        (19, "Fixed beacon: special mark"),
+        (20, "Floating AtoN: cardinal N"),
+        (21, "Floating AtoN: cardinal E"),
+        (22, "Floating AtoN: cardinal S"),
+        (23, "Floating AtoN: cardinal W"),
+        (24, "Floating AtoN: port hand mark"),
+        (25, "Floating AtoN: starboard hand mark"),
+        (26, "Floating AtoN: preferred channel port hand"),
+        (27, "Floating AtoN: preferred channel starboard hand"),
+        (28, "Floating AtoN: isolated danger"),
+        (29, "Floating AtoN: safe water"),
+        (30, "Floating AtoN: special mark"),
+        (31, "Floating AtoN: light vessel/LANBY/rigs"),
+    ],
+};
+
+/// A flag which is either set or not.
+pub static YES_NO: Lookup = Lookup {
+    name: "Yes/No",
+    values: &[(0, "No"), (1, "Yes")],
+};
+
+/// How many times an AIS message has been repeated.
+pub static REPEAT_INDICATOR: Lookup = Lookup {
+    name: "Repeat Indicator",
+    values: &[
+        (0, "Initial"),
+        (1, "First retransmission"),
+        (2, "Second retransmission"),
+        (3, "Final retransmission"),
+    ],
+};
+
+/// Accuracy of an AIS position.
+pub static POSITION_ACCURACY: Lookup = Lookup {
+    name: "Position Accuracy",
+    values: &[(0, "Low"), (1, "High")],
+};
+
+/// Whether an AIS transceiver uses Receiver Autonomous Integrity Monitoring.
+pub static RAIM_FLAG: Lookup = Lookup {
+    name: "RAIM Flag",
+    values: &[(0, "Not in use"), (1, "In use")],
+};
+
+/// Second of the UTC minute an AIS report was generated. Values below 60 are the second itself.
+pub static AIS_TIME_STAMP: Lookup = Lookup {
+    name: "AIS Time Stamp",
+    values: &[
+        (60, "Not available"),
+        (61, "Manual input mode"),
+        (62, "Dead reckoning mode"),
+        (63, "Positioning system is inoperative"),
+    ],
+};
+
+/// How an AIS message went through the transceiver which put it on the bus.
+pub static AIS_TRANSCEIVER: Lookup = Lookup {
+    name: "AIS Transceiver Information",
+    values: &[
+        (0, "Channel A VDL reception"),
+        (1, "Channel B VDL reception"),
+        (2, "Channel A VDL transmission"),
+        (3, "Channel B VDL transmission"),
+        (4, "Own information not broadcast"),
+        (5, "Reserved"),
+    ],
+};
+
+/// Whether an AIS unit is in assigned mode.
+pub static AIS_ASSIGNED_MODE: Lookup = Lookup {
+    name: "AIS Assigned Mode",
+    values: &[(0, "Autonomous and continuous"), (1, "Assigned mode")],
+};
+
+/// Whether the data terminal equipment of an AIS unit is ready.
+pub static AIS_DTE: Lookup = Lookup {
+    name: "AIS DTE",
+    values: &[(0, "Available"), (1, "Not available")],
+};
+
+/// Kind of class B AIS unit.
+pub static AIS_UNIT_TYPE: Lookup = Lookup {
+    name: "AIS Unit Type",
+    values: &[(0, "SOTDMA"), (1, "CS")],
+};
+
+/// Which part of the marine band a class B AIS unit can use.
+pub static AIS_BAND: Lookup = Lookup {
+    name: "AIS Band",
+    values: &[
+        (0, "Top 525 kHz of marine band"),
+        (1, "Entire marine band"),
+    ],
+};
+
+/// Whether a vessel is engaged in a special maneuver, as sent by AIS.
+pub static AIS_SPECIAL_MANEUVER: Lookup = Lookup {
+    name: "AIS Special Maneuver",
+    values: &[
+        (0, "Not available"),
+        (1, "Not engaged in special maneuver"),
+        (2, "Engaged in special maneuver"),
+        (3, "Reserved"),
+    ],
+};
+
+/// Whether a direction is from true or magnetic north.
+pub static DIRECTION_REFERENCE: Lookup = Lookup {
+    name: "Direction Reference",
+    values: &[(0, "True"), (1, "Magnetic"), (2, "Error")],
+};
+
+/// Where a magnetic variation comes from.
+pub static MAGNETIC_VARIATION_SOURCE: Lookup = Lookup {
+    name: "Magnetic Variation Source",
+    values: &[
+        (0, "Manual"),
+        (1, "Automatic Chart"),
+        (2, "Automatic Table"),
+        (3, "Automatic Calculation"),
+        (4, "WMM 2000"),
+        (5, "WMM 2005"),
+        (6, "WMM 2010"),
+        (7, "WMM 2015"),
+        (8, "WMM 2020"),
+    ],
+};
+
+/// Satellite systems a GNSS fix is made from.
+pub static GNSS_TYPE: Lookup = Lookup {
+    name: "GNSS Type",
+    values: &[
+        (0, "GPS"),
+        (1, "GLONASS"),
+        (2, "GPS+GLONASS"),
+        (3, "GPS+SBAS/WAAS"),
+        (4, "GPS+SBAS/WAAS+GLONASS"),
+        (5, "Chayka"),
+        (6, "Integrated"),
+        (7, "Surveyed"),
+        (8, "Galileo"),
+    ],
+};
+
+/// How a GNSS fix is made.
+pub static GNSS_METHOD: Lookup = Lookup {
+    name: "GNSS Method",
+    values: &[
+        (0, "No GNSS"),
+        (1, "GNSS fix"),
+        (2, "DGNSS fix"),
+        (3, "Precise GNSS"),
+        (4, "RTK Fixed Integer"),
+        (5, "RTK float"),
+        (6, "Estimated (DR) mode"),
+        (7, "Manual Input"),
+        (8, "Simulate mode"),
+    ],
+};
+
+/// Integrity checking of a GNSS fix.
+pub static GNSS_INTEGRITY: Lookup = Lookup {
+    name: "GNSS Integrity",
+    values: &[(0, "No integrity checking"), (1, "Safe"), (2, "Caution")],
+};
+
+/// How a cross track error or other measurement is found.
+pub static XTE_MODE: Lookup = Lookup {
+    name: "XTE Mode",
+    values: &[
+        (0, "Autonomous"),
+        (1, "Differential enhanced"),
+        (2, "Estimated"),
+        (3, "Simulator"),
+        (4, "Manual"),
+    ],
+};
+
+/// How the course and distance to a waypoint are calculated.
+pub static BEARING_CALCULATION: Lookup = Lookup {
+    name: "Bearing Calculation",
+    values: &[(0, "Great Circle"), (1, "Rhumb Line")],
+};
+
+/// Which way a route is being followed.
+pub static ROUTE_DIRECTION: Lookup = Lookup {
+    name: "Route Direction",
+    values: &[(0, "Forward"), (1, "Reverse")],
+};
+
+/// What a wind speed and angle are measured relative to.
+pub static WIND_REFERENCE: Lookup = Lookup {
+    name: "Wind Reference",
+    values: &[
+        (0, "True (ground referenced to North)"),
+        (1, "Magnetic (ground referenced to Magnetic North)"),
+        (2, "Apparent"),
+        (3, "True (boat referenced)"),
+        (4, "True (water referenced)"),
+    ],
+};
+
+/// Kind of sensor measuring speed through the water.
+pub static WATER_REFERENCE: Lookup = Lookup {
+    name: "Water Reference",
+    values: &[
+        (0, "Paddle wheel"),
+        (1, "Pitot tube"),
+        (2, "Doppler"),
+        (3, "Correlation (ultra sound)"),
+        (4, "Electro Magnetic"),
+    ],
+};
+
+/// Whether something is switched off or on.
+pub static OFF_ON: Lookup = Lookup {
+    name: "Off On",
+    values: &[(0, "Off"), (1, "On"), (2, "Error")],
+};
+
+/// Kind of DC source.
+pub static DC_SOURCE: Lookup = Lookup {
+    name: "DC Source",
+    values: &[
+        (0, "Battery"),
+        (1, "Alternator"),
+        (2, "Convertor"),
+        (3, "Solar Cell"),
+        (4, "Wind Generator"),
+    ],
+};
+
+/// What a battery charger is doing.
+pub static CHARGER_STATE: Lookup = Lookup {
+    name: "Charger State",
+    values: &[
+        (0, "Not charging"),
+        (1, "Bulk"),
+        (2, "Absorption"),
+        (3, "Overcharge"),
+        (4, "Equalise"),
+        (5, "Float"),
+        (6, "No Float"),
+        (7, "Constant VI"),
+        (8, "Disabled"),
+        (9, "Fault"),
+    ],
+};
+
+/// How a battery charger shares a battery with other chargers.
+pub static CHARGER_MODE: Lookup = Lookup {
+    name: "Charger Mode",
+    values: &[(0, "Standalone"), (1, "Primary"), (2, "Secondary"), (3, "Echo")],
+};
+
+/// What an inverter is doing.
+pub static INVERTER_STATE: Lookup = Lookup {
+    name: "Inverter State",
+    values: &[
+        (0, "Invert"),
+        (1, "AC passthru"),
+        (2, "Load sense"),
+        (3, "Fault"),
+        (4, "Disabled"),
+    ],
+};
+
+/// What a combined charger and inverter is doing.
+pub static CONVERTER_STATE: Lookup = Lookup {
+    name: "Converter State",
+    values: &[
+        (0, "Off"),
+        (1, "Low Power Mode"),
+        (2, "Fault"),
+        (3, "Bulk"),
+        (4, "Absorption"),
+        (5, "Float"),
+        (6, "Storage"),
+        (7, "Equalise"),
+        (8, "Pass thru"),
+        (9, "Inverting"),
+        (10, "Assisting"),
+    ],
+};
+
+/// Condition of a monitored quantity.
+pub static GOOD_WARNING_ERROR: Lookup = Lookup {
+    name: "Good Warning Error",
+    values: &[(0, "Good"), (1, "Warning"), (2, "Error")],
+};
+
+/// Whether an AC current leads or lags its voltage.
+pub static POWER_FACTOR: Lookup = Lookup {
+    name: "Power Factor",
+    values: &[(0, "Leading"), (1, "Lagging"), (2, "Error")],
+};
+
+/// Which engine of a vessel a message is about.
+pub static ENGINE_INSTANCE: Lookup = Lookup {
+    name: "Engine Instance",
+    values: &[(0, "Single Engine or Dual Engine Port"), (1, "Dual Engine Starboard")],
+};
+
+/// Gear a transmission is in.
+pub static GEAR_STATUS: Lookup = Lookup {
+    name: "Gear Status",
+    values: &[(0, "Forward"), (1, "Neutral"), (2, "Reverse")],
+};
+
+/// What a tank holds.
+pub static TANK_TYPE: Lookup = Lookup {
+    name: "Tank Type",
+    values: &[
+        (0, "Fuel"),
+        (1, "Water"),
+        (2, "Gray water"),
+        (3, "Live well"),
+        (4, "Oil"),
+        (5, "Black water"),
+        (6, "Fuel (gasoline)"),
+        (14, "Error"),
+    ],
+};
+
+/// Which way a rudder is ordered to move.
+pub static DIRECTION_RUDDER: Lookup = Lookup {
+    name: "Direction Rudder",
+    values: &[(0, "No Order"), (1, "Move to starboard"), (2, "Move to port")],
+};
+
+/// What is steering a vessel.
+pub static STEERING_MODE: Lookup = Lookup {
+    name: "Steering Mode",
+    values: &[
+        (0, "Main Steering"),
+        (1, "Non-Follow-up Device"),
+        (2, "Follow-up Device"),
+        (3, "Heading Control Standalone"),
+        (4, "Heading Control"),
+        (5, "Track Control"),
+    ],
+};
+
+/// What limits how a vessel turns.
+pub static TURN_MODE: Lookup = Lookup {
+    name: "Turn Mode",
+    values: &[
+        (0, "Rudder Limit controlled"),
+        (1, "turn rate controlled"),
+        (2, "radius controlled"),
+    ],
+};
+
+/// What a temperature is measured of.
+pub static TEMPERATURE_SOURCE: Lookup = Lookup {
+    name: "Temperature Source",
+    values: &[
+        (0, "Sea Temperature"),
+        (1, "Outside Temperature"),
+        (2, "Inside Temperature"),
+        (3, "Engine Room Temperature"),
+        (4, "Main Cabin Temperature"),
+        (5, "Live Well Temperature"),
+        (6, "Bait Well Temperature"),
+        (7, "Refrigeration Temperature"),
+        (8, "Heating System Temperature"),
+        (9, "Dew Point Temperature"),
+        (10, "Apparent Wind Chill Temperature"),
+        (11, "Theoretical Wind Chill Temperature"),
+        (12, "Heat Index Temperature"),
+        (13, "Freezer Temperature"),
+        (14, "Exhaust Gas Temperature"),
+        (15, "Shaft Seal Temperature"),
+    ],
+};
+
+/// Where a humidity is measured.
+pub static HUMIDITY_SOURCE: Lookup = Lookup {
+    name: "Humidity Source",
+    values: &[(0, "Inside"), (1, "Outside")],
+};
+
+/// What a pressure is measured of.
+pub static PRESSURE_SOURCE: Lookup = Lookup {
+    name: "Pressure Source",
+    values: &[
+        (0, "Atmospheric"),
+        (1, "Water"),
+        (2, "Steam"),
+        (3, "Compressed Air"),
+        (4, "Hydraulic"),
+        (5, "Filter"),
+        (6, "AltimeterSetting"),
+        (7, "Oil"),
+        (8, "Fuel"),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use registry;
+
+    #[test]
+    fn names_are_distinct_and_values_sorted() {
+        for field in registry::registry().iter().flat_map(|pgn| pgn.fields.iter()) {
+            let lookup = match field.lookup {
+                Some(lookup) => lookup,
+                None => continue,
+            };
+            for (i, &(value, name)) in lookup.values.iter().enumerate() {
+                assert_eq!(lookup.value_of(name), Some(value), "{}: {}", lookup.name, name);
+                if i > 0 {
+                    assert!(lookup.values[i - 1].0 < value, "{}: {}", lookup.name, value);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,760 @@
+//! Sentences sent by GPS and other GNSS receivers.
+//!
+//! Latitudes are in degrees north and longitudes in degrees east, so southern latitudes and
+//! western longitudes are negative. Times are the UTC time of day, since midnight. Fields a
+//! receiver leaves empty, as most do until they have a fix, are `None`.
+
+use std::time::Duration;
+
+use calendar;
+
+use super::{Date, Nmea0183Error, Sentence};
+
+/// The quality of a GGA fix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixQuality {
+    /// No fix.
+    Invalid,
+    /// Standalone GPS fix.
+    Gps,
+    /// Differential GPS fix.
+    Differential,
+    /// Precise Positioning Service fix.
+    Pps,
+    /// Real Time Kinematic fix, with fixed integers.
+    RealTimeKinematic,
+    /// Real Time Kinematic fix, with floating integers.
+    FloatRtk,
+    /// Dead reckoning.
+    Estimated,
+    /// Position entered by hand.
+    Manual,
+    /// Simulated position.
+    Simulator,
+}
+
+impl FixQuality {
+    fn from_number(number: u8) -> Option<FixQuality> {
+        Some(match number {
+            0 => FixQuality::Invalid,
+            1 => FixQuality::Gps,
+            2 => FixQuality::Differential,
+            3 => FixQuality::Pps,
+            4 => FixQuality::RealTimeKinematic,
+            5 => FixQuality::FloatRtk,
+            6 => FixQuality::Estimated,
+            7 => FixQuality::Manual,
+            8 => FixQuality::Simulator,
+            _ => return None,
+        })
+    }
+}
+
+/// The mode indicator added to GLL, RMC and VTG by NMEA 0183 version 2.3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Autonomous fix.
+    Autonomous,
+    /// Differential fix.
+    Differential,
+    /// Dead reckoning.
+    Estimated,
+    /// Real Time Kinematic fix, with floating integers.
+    FloatRtk,
+    /// Position entered by hand.
+    Manual,
+    /// No valid fix.
+    NotValid,
+    /// Precise fix.
+    Precise,
+    /// Real Time Kinematic fix, with fixed integers.
+    RealTimeKinematic,
+    /// Simulated position.
+    Simulator,
+}
+
+impl Mode {
+    fn from_char(c: char) -> Option<Mode> {
+        Some(match c {
+            'A' => Mode::Autonomous,
+            'D' => Mode::Differential,
+            'E' => Mode::Estimated,
+            'F' => Mode::FloatRtk,
+            'M' => Mode::Manual,
+            'N' => Mode::NotValid,
+            'P' => Mode::Precise,
+            'R' => Mode::RealTimeKinematic,
+            'S' => Mode::Simulator,
+            _ => return None,
+        })
+    }
+}
+
+/// How the receiver chooses between a 2D and 3D fix, in GSA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    /// Forced to one or the other.
+    Manual,
+    /// Chosen by the receiver.
+    Automatic,
+}
+
+/// The kind of fix, in GSA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixType {
+    /// No fix.
+    NoFix,
+    /// Horizontal fix only.
+    Fix2D,
+    /// Horizontal and vertical fix.
+    Fix3D,
+}
+
+/// Global Positioning System fix data.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use libnmea::nmea0183::{FixQuality, Gga, Sentence};
+///
+/// let line = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+/// let gga = Gga::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(gga.time, Some(Duration::from_secs(12 * 3600 + 35 * 60 + 19)));
+/// assert_eq!(gga.latitude, Some(48.1173));
+/// assert_eq!(gga.longitude, Some(11.516666666666667));
+/// assert_eq!(gga.quality, FixQuality::Gps);
+/// assert_eq!(gga.satellites, Some(8));
+/// assert_eq!(gga.altitude, Some(545.4));
+/// assert_eq!(gga.differential_age, None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gga {
+    /// Time of the fix.
+    pub time: Option<Duration>,
+    /// Latitude, in degrees.
+    pub latitude: Option<f64>,
+    /// Longitude, in degrees.
+    pub longitude: Option<f64>,
+    /// Quality of the fix.
+    pub quality: FixQuality,
+    /// Number of satellites used for the fix.
+    pub satellites: Option<u8>,
+    /// Horizontal dilution of precision.
+    pub hdop: Option<f64>,
+    /// Altitude of the antenna above mean sea level, in metres.
+    pub altitude: Option<f64>,
+    /// Height of the geoid above the WGS84 ellipsoid, in metres.
+    pub geoidal_separation: Option<f64>,
+    /// Time since the last differential correction, in seconds.
+    pub differential_age: Option<f64>,
+    /// ID of the differential reference station.
+    pub differential_station: Option<u16>,
+}
+
+impl Gga {
+    /// Decodes a GGA sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Gga, Nmea0183Error> {
+        sentence.expect("GGA")?;
+
+        let quality = sentence
+            .number(5)?
+            .and_then(FixQuality::from_number)
+            .ok_or(Nmea0183Error::Field(5))?;
+
+        Ok(Gga {
+            time: sentence.time(0)?,
+            latitude: sentence.latitude(1)?,
+            longitude: sentence.longitude(3)?,
+            quality,
+            satellites: sentence.number(6)?,
+            hdop: sentence.number(7)?,
+            altitude: sentence.number(8)?,
+            geoidal_separation: sentence.number(10)?,
+            differential_age: sentence.number(12)?,
+            differential_station: sentence.number(13)?,
+        })
+    }
+}
+
+/// Geographic position.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Gll, Sentence};
+///
+/// let line = "$GPGLL,4916.45,N,12311.12,W,225444,A*31";
+/// let gll = Gll::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(gll.longitude, Some(-123.18533333333333));
+/// assert!(gll.valid);
+/// assert_eq!(gll.mode, None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gll {
+    /// Latitude, in degrees.
+    pub latitude: Option<f64>,
+    /// Longitude, in degrees.
+    pub longitude: Option<f64>,
+    /// Time of the position.
+    pub time: Option<Duration>,
+    /// Whether the position is valid.
+    pub valid: bool,
+    /// Mode indicator, sent from NMEA 0183 version 2.3.
+    pub mode: Option<Mode>,
+}
+
+impl Gll {
+    /// Decodes a GLL sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Gll, Nmea0183Error> {
+        sentence.expect("GLL")?;
+
+        Ok(Gll {
+            latitude: sentence.latitude(0)?,
+            longitude: sentence.longitude(2)?,
+            time: sentence.time(4)?,
+            valid: sentence.status(5)?,
+            mode: mode(sentence, 6)?,
+        })
+    }
+}
+
+/// GNSS DOP and active satellites.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{FixType, Gsa, Selection, Sentence};
+///
+/// let line = "$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39";
+/// let gsa = Gsa::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(gsa.selection, Selection::Automatic);
+/// assert_eq!(gsa.fix, FixType::Fix3D);
+/// assert_eq!(gsa.satellites, vec![4, 5, 9, 12, 24]);
+/// assert_eq!(gsa.pdop, Some(2.5));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gsa {
+    /// How the kind of fix is chosen.
+    pub selection: Selection,
+    /// Kind of fix.
+    pub fix: FixType,
+    /// IDs of the satellites used for the fix.
+    pub satellites: Vec<u16>,
+    /// Position dilution of precision.
+    pub pdop: Option<f64>,
+    /// Horizontal dilution of precision.
+    pub hdop: Option<f64>,
+    /// Vertical dilution of precision.
+    pub vdop: Option<f64>,
+    /// GNSS system ID, sent from NMEA 0183 version 4.1. 1 is GPS, 2 GLONASS, 3 Galileo and
+    /// 4 BeiDou.
+    pub system: Option<u8>,
+}
+
+impl Gsa {
+    /// Decodes a GSA sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Gsa, Nmea0183Error> {
+        sentence.expect("GSA")?;
+
+        let selection = match sentence.character(0)? {
+            Some('M') => Selection::Manual,
+            Some('A') => Selection::Automatic,
+            _ => return Err(Nmea0183Error::Field(0)),
+        };
+        let fix = match sentence.number::<u8>(1)? {
+            Some(1) => FixType::NoFix,
+            Some(2) => FixType::Fix2D,
+            Some(3) => FixType::Fix3D,
+            _ => return Err(Nmea0183Error::Field(1)),
+        };
+
+        let mut satellites = Vec::new();
+        for index in 2..14 {
+            if let Some(id) = sentence.number(index)? {
+                satellites.push(id);
+            }
+        }
+
+        Ok(Gsa {
+            selection,
+            fix,
+            satellites,
+            pdop: sentence.number(14)?,
+            hdop: sentence.number(15)?,
+            vdop: sentence.number(16)?,
+            system: sentence.number(17)?,
+        })
+    }
+}
+
+/// A satellite in a GSV sentence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Satellite {
+    /// Satellite ID, the PRN number for GPS.
+    pub id: u16,
+    /// Elevation, in degrees.
+    pub elevation: Option<f64>,
+    /// Azimuth from true north, in degrees.
+    pub azimuth: Option<f64>,
+    /// Signal to noise ratio, in dB, or `None` when the satellite is not being tracked.
+    pub snr: Option<u8>,
+}
+
+/// GNSS satellites in view. Receivers send up to four satellites per sentence, so a full list
+/// takes several sentences.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Gsv, Sentence};
+///
+/// let line = "$GPGSV,2,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75";
+/// let gsv = Gsv::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(gsv.count, 2);
+/// assert_eq!(gsv.number, 1);
+/// assert_eq!(gsv.in_view, 8);
+/// assert_eq!(gsv.satellites.len(), 4);
+/// assert_eq!(gsv.satellites[1].azimuth, Some(308.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gsv {
+    /// Number of sentences in the list.
+    pub count: u8,
+    /// Number of this sentence in the list, from 1.
+    pub number: u8,
+    /// Number of satellites in view.
+    pub in_view: u16,
+    /// The satellites in this sentence.
+    pub satellites: Vec<Satellite>,
+    /// GNSS signal ID, sent from NMEA 0183 version 4.1.
+    pub signal: Option<u8>,
+}
+
+impl Gsv {
+    /// Decodes a GSV sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Gsv, Nmea0183Error> {
+        sentence.expect("GSV")?;
+
+        let count = sentence.number(0)?.ok_or(Nmea0183Error::Field(0))?;
+        let number = sentence.number(1)?.ok_or(Nmea0183Error::Field(1))?;
+        let in_view = sentence.number(2)?.ok_or(Nmea0183Error::Field(2))?;
+
+        let fields = sentence.fields.len();
+        let groups = fields.saturating_sub(3) / 4;
+        let signal = if fields > 3 && (fields - 3) % 4 == 1 {
+            sentence.number(fields - 1)?
+        } else {
+            None
+        };
+
+        let mut satellites = Vec::new();
+        for group in 0..groups {
+            let index = 3 + group * 4;
+            if let Some(id) = sentence.number(index)? {
+                satellites.push(Satellite {
+                    id,
+                    elevation: sentence.number(index + 1)?,
+                    azimuth: sentence.number(index + 2)?,
+                    snr: sentence.number(index + 3)?,
+                });
+            }
+        }
+
+        Ok(Gsv {
+            count,
+            number,
+            in_view,
+            satellites,
+            signal,
+        })
+    }
+}
+
+/// Recommended minimum specific GNSS data.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use libnmea::nmea0183::{Rmc, Sentence};
+///
+/// let line = "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A";
+/// let rmc = Rmc::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert!(rmc.valid);
+/// assert_eq!(rmc.speed, Some(22.4));
+/// assert_eq!(rmc.magnetic_variation, Some(-3.1));
+/// assert_eq!(rmc.timestamp(), Some(Duration::from_secs(764426119)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rmc {
+    /// Time of the fix.
+    pub time: Option<Duration>,
+    /// Whether the fix is valid.
+    pub valid: bool,
+    /// Latitude, in degrees.
+    pub latitude: Option<f64>,
+    /// Longitude, in degrees.
+    pub longitude: Option<f64>,
+    /// Speed over ground, in knots.
+    pub speed: Option<f64>,
+    /// Course over ground from true north, in degrees.
+    pub course: Option<f64>,
+    /// Date of the fix. The year is sent as two digits, which are taken to be from 1980 to 2079.
+    pub date: Option<Date>,
+    /// Magnetic variation, in degrees. Westerly variation is negative.
+    pub magnetic_variation: Option<f64>,
+    /// Mode indicator, sent from NMEA 0183 version 2.3.
+    pub mode: Option<Mode>,
+}
+
+impl Rmc {
+    /// Decodes an RMC sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Rmc, Nmea0183Error> {
+        sentence.expect("RMC")?;
+
+        let date = match sentence.field(8) {
+            Some(field) => Some(parse_date(field).ok_or(Nmea0183Error::Field(8))?),
+            None => None,
+        };
+
+        Ok(Rmc {
+            time: sentence.time(0)?,
+            valid: sentence.status(1)?,
+            latitude: sentence.latitude(2)?,
+            longitude: sentence.longitude(4)?,
+            speed: sentence.number(6)?,
+            course: sentence.number(7)?,
+            date,
+            magnetic_variation: sentence.variation(9)?,
+            mode: mode(sentence, 11)?,
+        })
+    }
+
+    /// The date and time of the fix, since the Unix epoch.
+    pub fn timestamp(&self) -> Option<Duration> {
+        self.date?.with_time(self.time?)
+    }
+}
+
+/// Course over ground and ground speed.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Mode, Sentence, Vtg};
+///
+/// let line = "$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,A*25";
+/// let vtg = Vtg::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(vtg.course_true, Some(54.7));
+/// assert_eq!(vtg.speed_kmh, Some(10.2));
+/// assert_eq!(vtg.mode, Some(Mode::Autonomous));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vtg {
+    /// Course over ground from true north, in degrees.
+    pub course_true: Option<f64>,
+    /// Course over ground from magnetic north, in degrees.
+    pub course_magnetic: Option<f64>,
+    /// Speed over ground, in knots.
+    pub speed_knots: Option<f64>,
+    /// Speed over ground, in kilometres per hour.
+    pub speed_kmh: Option<f64>,
+    /// Mode indicator, sent from NMEA 0183 version 2.3.
+    pub mode: Option<Mode>,
+}
+
+impl Vtg {
+    /// Decodes a VTG sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Vtg, Nmea0183Error> {
+        sentence.expect("VTG")?;
+
+        Ok(Vtg {
+            course_true: sentence.number(0)?,
+            course_magnetic: sentence.number(2)?,
+            speed_knots: sentence.number(4)?,
+            speed_kmh: sentence.number(6)?,
+            mode: mode(sentence, 8)?,
+        })
+    }
+}
+
+/// Time and date.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use libnmea::nmea0183::{Date, Sentence, Zda};
+///
+/// let line = "$GPZDA,201530.00,04,07,2002,00,00*60";
+/// let zda = Zda::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(zda.date, Some(Date { year: 2002, month: 7, day: 4 }));
+/// assert_eq!(zda.timestamp(), Some(Duration::from_secs(1025813730)));
+///
+/// let line = "$GPZDA,201530.00,31,02,2002,00,00*63";
+/// assert!(Zda::from_sentence(&Sentence::parse(line).unwrap()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Zda {
+    /// UTC time of day.
+    pub time: Option<Duration>,
+    /// UTC date.
+    pub date: Option<Date>,
+    /// Hours of the local time zone's offset from UTC.
+    pub zone_hours: Option<i8>,
+    /// Minutes of the local time zone's offset from UTC, with the same sign as the hours.
+    pub zone_minutes: Option<u8>,
+}
+
+impl Zda {
+    /// Decodes a ZDA sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Zda, Nmea0183Error> {
+        sentence.expect("ZDA")?;
+
+        let day: Option<u32> = sentence.number(1)?;
+        let month: Option<u32> = sentence.number(2)?;
+        let year: Option<i64> = sentence.number(3)?;
+        let date = match (year, month, day) {
+            (Some(year), Some(month), Some(day)) => {
+                if !(1..=12).contains(&month) {
+                    return Err(Nmea0183Error::Field(2));
+                }
+                if !calendar::is_valid_date(year, month, day) {
+                    return Err(Nmea0183Error::Field(1));
+                }
+                Some(Date { year, month, day })
+            }
+            _ => None,
+        };
+
+        Ok(Zda {
+            time: sentence.time(0)?,
+            date,
+            zone_hours: sentence.number(4)?,
+            zone_minutes: sentence.number(5)?,
+        })
+    }
+
+    /// The UTC date and time, since the Unix epoch.
+    pub fn timestamp(&self) -> Option<Duration> {
+        self.date?.with_time(self.time?)
+    }
+}
+
+/// The optional mode indicator at an index.
+fn mode(sentence: &Sentence, index: usize) -> Result<Option<Mode>, Nmea0183Error> {
+    match sentence.character(index)? {
+        Some(c) => Mode::from_char(c)
+            .map(Some)
+            .ok_or(Nmea0183Error::Field(index)),
+        None => Ok(None),
+    }
+}
+
+/// Parses a date written as `ddmmyy`.
+fn parse_date(text: &str) -> Option<Date> {
+    if text.len() != 6 || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let day: u32 = text[..2].parse().ok()?;
+    let month: u32 = text[2..4].parse().ok()?;
+    let year: i64 = text[4..].parse().ok()?;
+    let year = if year < 80 { 2000 + year } else { 1900 + year };
+    if !calendar::is_valid_date(year, month, day) {
+        return None;
+    }
+
+    Some(Date { year, month, day })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nmea0183::Start;
+
+    fn sentence(formatter: &str, fields: &str) -> Sentence {
+        Sentence {
+            start: Start::Parametric,
+            talker: "GP".to_string(),
+            formatter: formatter.to_string(),
+            fields: fields.split(',').map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn wrong_sentence() {
+        let gll = sentence("GLL", "4916.45,N,12311.12,W,225444,A");
+
+        assert_eq!(Gga::from_sentence(&gll), Err(Nmea0183Error::WrongSentence));
+        assert_eq!(Rmc::from_sentence(&gll), Err(Nmea0183Error::WrongSentence));
+        assert_eq!(Zda::from_sentence(&gll), Err(Nmea0183Error::WrongSentence));
+    }
+
+    #[test]
+    fn gga_without_fix() {
+        let gga = Gga::from_sentence(&sentence("GGA", ",,,,,0,00,,,M,,M,,")).unwrap();
+
+        assert_eq!(gga.quality, FixQuality::Invalid);
+        assert_eq!(gga.time, None);
+        assert_eq!(gga.latitude, None);
+        assert_eq!(gga.satellites, Some(0));
+    }
+
+    #[test]
+    fn gga_quality() {
+        for quality in &["", "9", "-1", "x"] {
+            let fields = format!(
+                "123519,4807.038,N,01131.000,E,{},08,0.9,545.4,M,46.9,M,,",
+                quality
+            );
+            assert_eq!(
+                Gga::from_sentence(&sentence("GGA", &fields)),
+                Err(Nmea0183Error::Field(5)),
+                "{}",
+                quality
+            );
+        }
+    }
+
+    #[test]
+    fn gll_status_and_mode() {
+        assert_eq!(
+            Gll::from_sentence(&sentence("GLL", "4916.45,N,12311.12,W,225444,")),
+            Err(Nmea0183Error::Field(5))
+        );
+        assert_eq!(
+            Gll::from_sentence(&sentence("GLL", "4916.45,N,12311.12,W,225444,A,X")),
+            Err(Nmea0183Error::Field(6))
+        );
+        assert_eq!(
+            Gll::from_sentence(&sentence("GLL", "4916.45,N,12311.12,W,2254,A")),
+            Err(Nmea0183Error::Field(4))
+        );
+    }
+
+    #[test]
+    fn gsa_selection_and_fix() {
+        assert_eq!(
+            Gsa::from_sentence(&sentence("GSA", "X,3,04,05,,,,,,,,,,,2.5,1.3,2.1")),
+            Err(Nmea0183Error::Field(0))
+        );
+        assert_eq!(
+            Gsa::from_sentence(&sentence("GSA", "A,4,04,05,,,,,,,,,,,2.5,1.3,2.1")),
+            Err(Nmea0183Error::Field(1))
+        );
+
+        let gsa = Gsa::from_sentence(&sentence("GSA", "M,1,,,,,,,,,,,,,,,")).unwrap();
+        assert_eq!(gsa.selection, Selection::Manual);
+        assert_eq!(gsa.fix, FixType::NoFix);
+        assert!(gsa.satellites.is_empty());
+        assert_eq!(gsa.pdop, None);
+    }
+
+    #[test]
+    fn gsv_groups_and_signal() {
+        let gsv = Gsv::from_sentence(&sentence("GSV", "3,3,10,,,,,31,10,,,1")).unwrap();
+
+        assert_eq!(gsv.satellites.len(), 1);
+        assert_eq!(gsv.satellites[0].id, 31);
+        assert_eq!(gsv.satellites[0].azimuth, None);
+        assert_eq!(gsv.signal, Some(1));
+
+        assert_eq!(
+            Gsv::from_sentence(&sentence("GSV", ",1,10")),
+            Err(Nmea0183Error::Field(0))
+        );
+        assert_eq!(
+            Gsv::from_sentence(&sentence("GSV", "3,1,10,x,1,2,3")),
+            Err(Nmea0183Error::Field(3))
+        );
+    }
+
+    #[test]
+    fn rmc_dates() {
+        let rmc = |date: &str| {
+            let fields = format!(
+                "123519,A,4807.038,N,01131.000,E,022.4,084.4,{},003.1,W",
+                date
+            );
+            Rmc::from_sentence(&sentence("RMC", &fields))
+        };
+
+        assert_eq!(
+            rmc("010179").unwrap().date,
+            Some(Date {
+                year: 2079,
+                month: 1,
+                day: 1
+            })
+        );
+        assert_eq!(rmc("010180").unwrap().date.unwrap().year, 1980);
+        assert_eq!(rmc("290200").unwrap().date.unwrap().day, 29);
+        assert_eq!(rmc("").unwrap().date, None);
+        assert_eq!(rmc("").unwrap().timestamp(), None);
+
+        for date in &[
+            "290201", "320194", "001394", "000194", "23039", "2303944", "23o394",
+        ] {
+            assert_eq!(rmc(date), Err(Nmea0183Error::Field(8)), "{}", date);
+        }
+    }
+
+    #[test]
+    fn rmc_void() {
+        let rmc = Rmc::from_sentence(&sentence("RMC", "123519,V,,,,,,,230394,,,N")).unwrap();
+
+        assert!(!rmc.valid);
+        assert_eq!(rmc.mode, Some(Mode::NotValid));
+        assert_eq!(rmc.timestamp(), Some(Duration::from_secs(764_426_119)));
+    }
+
+    #[test]
+    fn vtg_numbers() {
+        let vtg = Vtg::from_sentence(&sentence("VTG", "054.7,T,,M,005.5,N,010.2,K")).unwrap();
+
+        assert_eq!(vtg.course_true, Some(54.7));
+        assert_eq!(vtg.course_magnetic, None);
+        assert_eq!(vtg.mode, None);
+        assert_eq!(
+            Vtg::from_sentence(&sentence("VTG", "054.7,T,034.4,M,5,5,N,010.2,K")),
+            Err(Nmea0183Error::Field(6))
+        );
+        assert_eq!(
+            Vtg::from_sentence(&sentence("VTG", "054..7,T,034.4,M,005.5,N,010.2,K")),
+            Err(Nmea0183Error::Field(0))
+        );
+    }
+
+    #[test]
+    fn zda_dates() {
+        let zda = Zda::from_sentence(&sentence("ZDA", "201530.00,04,07,2002,-05,30")).unwrap();
+        assert_eq!(zda.zone_hours, Some(-5));
+        assert_eq!(zda.zone_minutes, Some(30));
+        assert_eq!(zda.timestamp(), Some(Duration::from_secs(1_025_813_730)));
+
+        let zda = Zda::from_sentence(&sentence("ZDA", "201530.00,04,,2002,,")).unwrap();
+        assert_eq!(zda.date, None);
+
+        assert_eq!(
+            Zda::from_sentence(&sentence("ZDA", "201530.00,04,13,2002,00,00")),
+            Err(Nmea0183Error::Field(2))
+        );
+        assert_eq!(
+            Zda::from_sentence(&sentence("ZDA", "201530.00,00,07,2002,00,00")),
+            Err(Nmea0183Error::Field(1))
+        );
+        assert_eq!(
+            Zda::from_sentence(&sentence("ZDA", "201530.00,04,07,2002,-500,00")),
+            Err(Nmea0183Error::Field(4))
+        );
+    }
+}
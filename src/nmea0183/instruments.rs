@@ -0,0 +1,604 @@
+//! Sentences sent by compasses, wind, depth and speed instruments, rudder angle indicators and
+//! other transducers.
+//!
+//! Angles are in degrees, and directions with a `_true` or `_magnetic` suffix are from true or
+//! magnetic north. Values in the unit a sentence chooses are given with their
+//! [Unit](../../enum.Unit.html). Fields an instrument leaves empty are `None`.
+
+use Unit;
+
+use super::{Nmea0183Error, Sentence};
+
+/// Heading, deviation and variation.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Hdg, Sentence};
+///
+/// let line = "$HCHDG,98.3,0.0,E,12.6,W*57";
+/// let hdg = Hdg::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(hdg.heading, Some(98.3));
+/// assert_eq!(hdg.deviation, Some(0.0));
+/// assert_eq!(hdg.variation, Some(-12.6));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hdg {
+    /// Heading measured by the magnetic sensor, in degrees.
+    pub heading: Option<f64>,
+    /// Magnetic deviation, in degrees. Westerly deviation is negative.
+    pub deviation: Option<f64>,
+    /// Magnetic variation, in degrees. Westerly variation is negative.
+    pub variation: Option<f64>,
+}
+
+impl Hdg {
+    /// Decodes an HDG sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Hdg, Nmea0183Error> {
+        sentence.expect("HDG")?;
+
+        Ok(Hdg {
+            heading: sentence.number(0)?,
+            deviation: sentence.variation(1)?,
+            variation: sentence.variation(3)?,
+        })
+    }
+}
+
+/// Heading from true north.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Hdt, Sentence};
+///
+/// let line = "$HEHDT,274.07,T*19";
+/// let hdt = Hdt::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(hdt.heading_true, Some(274.07));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hdt {
+    /// Heading, in degrees.
+    pub heading_true: Option<f64>,
+}
+
+impl Hdt {
+    /// Decodes an HDT sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Hdt, Nmea0183Error> {
+        sentence.expect("HDT")?;
+
+        Ok(Hdt {
+            heading_true: sentence.number(0)?,
+        })
+    }
+}
+
+/// What a wind angle is measured from, in MWV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindReference {
+    /// Apparent wind, from the bow.
+    Relative,
+    /// True wind, from the bow, with the vessel's speed taken away.
+    Theoretical,
+}
+
+/// Wind speed and angle.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Mwv, Sentence, WindReference};
+/// use libnmea::Unit;
+///
+/// let line = "$WIMWV,214.8,R,0.1,K,A*28";
+/// let mwv = Mwv::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(mwv.angle, Some(214.8));
+/// assert_eq!(mwv.reference, WindReference::Relative);
+/// assert_eq!(mwv.speed, Some(0.1));
+/// assert_eq!(mwv.speed_unit, Some(Unit::KilometersPerHour));
+/// assert!(mwv.valid);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mwv {
+    /// Wind angle from the bow, clockwise, in degrees.
+    pub angle: Option<f64>,
+    /// What the angle is measured from.
+    pub reference: WindReference,
+    /// Wind speed, in `speed_unit`.
+    pub speed: Option<f64>,
+    /// Unit of the wind speed: knots, metres per second or kilometres per hour.
+    pub speed_unit: Option<Unit>,
+    /// Whether the data is valid.
+    pub valid: bool,
+}
+
+impl Mwv {
+    /// Decodes an MWV sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Mwv, Nmea0183Error> {
+        sentence.expect("MWV")?;
+
+        let reference = match sentence.character(1)? {
+            Some('R') => WindReference::Relative,
+            Some('T') => WindReference::Theoretical,
+            _ => return Err(Nmea0183Error::Field(1)),
+        };
+        let speed_unit = match sentence.character(3)? {
+            Some('N') => Some(Unit::Knots),
+            Some('M') => Some(Unit::MetersPerSecond),
+            Some('K') => Some(Unit::KilometersPerHour),
+            Some(_) => return Err(Nmea0183Error::Field(3)),
+            None => None,
+        };
+
+        Ok(Mwv {
+            angle: sentence.number(0)?,
+            reference,
+            speed: sentence.number(2)?,
+            speed_unit,
+            valid: sentence.status(4)?,
+        })
+    }
+}
+
+/// Wind direction and speed, relative to the earth.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Mwd, Sentence};
+///
+/// let line = "$WIMWD,12.4,T,,M,8.2,N,4.2,M*4F";
+/// let mwd = Mwd::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(mwd.direction_true, Some(12.4));
+/// assert_eq!(mwd.direction_magnetic, None);
+/// assert_eq!(mwd.speed_mps, Some(4.2));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mwd {
+    /// Direction the wind blows from, from true north, in degrees.
+    pub direction_true: Option<f64>,
+    /// Direction the wind blows from, from magnetic north, in degrees.
+    pub direction_magnetic: Option<f64>,
+    /// Wind speed, in knots.
+    pub speed_knots: Option<f64>,
+    /// Wind speed, in metres per second.
+    pub speed_mps: Option<f64>,
+}
+
+impl Mwd {
+    /// Decodes an MWD sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Mwd, Nmea0183Error> {
+        sentence.expect("MWD")?;
+
+        Ok(Mwd {
+            direction_true: sentence.number(0)?,
+            direction_magnetic: sentence.number(2)?,
+            speed_knots: sentence.number(4)?,
+            speed_mps: sentence.number(6)?,
+        })
+    }
+}
+
+/// Depth below the transducer.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Dbt, Sentence};
+///
+/// let line = "$SDDBT,7.8,f,2.4,M,1.3,F*0D";
+/// let dbt = Dbt::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(dbt.depth_metres, Some(2.4));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dbt {
+    /// Depth, in feet.
+    pub depth_feet: Option<f64>,
+    /// Depth, in metres.
+    pub depth_metres: Option<f64>,
+    /// Depth, in fathoms.
+    pub depth_fathoms: Option<f64>,
+}
+
+impl Dbt {
+    /// Decodes a DBT sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Dbt, Nmea0183Error> {
+        sentence.expect("DBT")?;
+
+        Ok(Dbt {
+            depth_feet: sentence.number(0)?,
+            depth_metres: sentence.number(2)?,
+            depth_fathoms: sentence.number(4)?,
+        })
+    }
+}
+
+/// Depth, with the offset of the transducer.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Dpt, Sentence};
+///
+/// let line = "$SDDPT,2.4,-0.5,100*64";
+/// let dpt = Dpt::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(dpt.depth, Some(2.4));
+/// assert_eq!(dpt.offset, Some(-0.5));
+/// assert_eq!(dpt.range, Some(100.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dpt {
+    /// Depth below the transducer, in metres.
+    pub depth: Option<f64>,
+    /// Offset of the transducer, in metres. Positive offsets are the distance from the transducer
+    /// to the waterline, and negative offsets the distance from the transducer to the keel.
+    pub offset: Option<f64>,
+    /// Maximum range of the transducer, in metres, sent from NMEA 0183 version 3.0.
+    pub range: Option<f64>,
+}
+
+impl Dpt {
+    /// Decodes a DPT sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Dpt, Nmea0183Error> {
+        sentence.expect("DPT")?;
+
+        Ok(Dpt {
+            depth: sentence.number(0)?,
+            offset: sentence.number(1)?,
+            range: sentence.number(2)?,
+        })
+    }
+}
+
+/// Speed through the water and heading.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Sentence, Vhw};
+///
+/// let line = "$VWVHW,,T,,M,3.5,N,6.5,K*51";
+/// let vhw = Vhw::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(vhw.heading_true, None);
+/// assert_eq!(vhw.speed_knots, Some(3.5));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vhw {
+    /// Heading, from true north, in degrees.
+    pub heading_true: Option<f64>,
+    /// Heading, from magnetic north, in degrees.
+    pub heading_magnetic: Option<f64>,
+    /// Speed through the water, in knots.
+    pub speed_knots: Option<f64>,
+    /// Speed through the water, in kilometres per hour.
+    pub speed_kmh: Option<f64>,
+}
+
+impl Vhw {
+    /// Decodes a VHW sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Vhw, Nmea0183Error> {
+        sentence.expect("VHW")?;
+
+        Ok(Vhw {
+            heading_true: sentence.number(0)?,
+            heading_magnetic: sentence.number(2)?,
+            speed_knots: sentence.number(4)?,
+            speed_kmh: sentence.number(6)?,
+        })
+    }
+}
+
+/// Water temperature.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Mtw, Sentence};
+///
+/// let line = "$YXMTW,17.75,C*26";
+/// let mtw = Mtw::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(mtw.temperature, Some(17.75));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mtw {
+    /// Temperature, in degrees Celsius.
+    pub temperature: Option<f64>,
+}
+
+impl Mtw {
+    /// Decodes an MTW sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Mtw, Nmea0183Error> {
+        sentence.expect("MTW")?;
+
+        match sentence.character(1)? {
+            Some('C') | None => {}
+            Some(_) => return Err(Nmea0183Error::Field(1)),
+        }
+
+        Ok(Mtw {
+            temperature: sentence.number(0)?,
+        })
+    }
+}
+
+/// Rudder angle.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Rsa, Sentence};
+///
+/// let line = "$IIRSA,-4.5,A,,V*55";
+/// let rsa = Rsa::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(rsa.starboard, Some(-4.5));
+/// assert_eq!(rsa.port, None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rsa {
+    /// Angle of the starboard rudder, or the only rudder, in degrees. Negative angles turn the
+    /// vessel to port. `None` if the sender marks it invalid.
+    pub starboard: Option<f64>,
+    /// Angle of the port rudder, in degrees. `None` if the sender marks it invalid.
+    pub port: Option<f64>,
+}
+
+impl Rsa {
+    /// Decodes an RSA sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Rsa, Nmea0183Error> {
+        sentence.expect("RSA")?;
+
+        Ok(Rsa {
+            starboard: rudder(sentence, 0)?,
+            port: rudder(sentence, 2)?,
+        })
+    }
+}
+
+/// A measurement in an XDR sentence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transducer {
+    /// Type of transducer, such as `C` for temperature or `P` for pressure.
+    pub kind: char,
+    /// The measurement, in `unit_code`.
+    pub value: Option<f64>,
+    /// The unit the sender gave, such as `C` for degrees Celsius or `B` for bars.
+    pub unit_code: Option<char>,
+    /// The unit, if it is one the crate has.
+    pub unit: Option<Unit>,
+    /// Name of the transducer.
+    pub name: String,
+}
+
+/// Measurements from any number of transducers.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::{Sentence, Xdr};
+/// use libnmea::Unit;
+///
+/// let line = "$IIXDR,C,19.52,C,TempAir,P,1.02481,B,Barometer*7E";
+/// let xdr = Xdr::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///
+/// assert_eq!(xdr.transducers.len(), 2);
+/// assert_eq!(xdr.transducers[0].unit, Some(Unit::DegreesCelcius));
+/// assert_eq!(xdr.transducers[1].value, Some(1.02481));
+/// assert_eq!(xdr.transducers[1].name, "Barometer");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Xdr {
+    /// The measurements, one for each group of type, value, unit and name.
+    pub transducers: Vec<Transducer>,
+}
+
+impl Xdr {
+    /// Decodes an XDR sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Xdr, Nmea0183Error> {
+        sentence.expect("XDR")?;
+
+        if sentence.fields.len() % 4 != 0 {
+            return Err(Nmea0183Error::Field(sentence.fields.len()));
+        }
+
+        let mut transducers = Vec::new();
+        for index in (0..sentence.fields.len()).step_by(4) {
+            let kind = sentence
+                .character(index)?
+                .ok_or(Nmea0183Error::Field(index))?;
+            let unit_code = sentence.character(index + 2)?;
+            transducers.push(Transducer {
+                kind,
+                value: sentence.number(index + 1)?,
+                unit_code,
+                unit: unit_code.and_then(|code| transducer_unit(kind, code)),
+                name: sentence.fields[index + 3].clone(),
+            });
+        }
+
+        Ok(Xdr { transducers })
+    }
+}
+
+/// The angle at an index, if the status after it is valid.
+fn rudder(sentence: &Sentence, index: usize) -> Result<Option<f64>, Nmea0183Error> {
+    let angle = sentence.number(index)?;
+    match sentence.character(index + 1)? {
+        Some('A') => Ok(angle),
+        Some('V') | None => Ok(None),
+        Some(_) => Err(Nmea0183Error::Field(index + 1)),
+    }
+}
+
+/// The unit of an XDR measurement. Some unit codes mean different things for different types of
+/// transducer, so both are needed.
+fn transducer_unit(kind: char, code: char) -> Option<Unit> {
+    Some(match (kind, code) {
+        ('A', 'D') => Unit::Degrees,
+        ('C', 'C') => Unit::DegreesCelcius,
+        ('D', 'M') => Unit::Meters,
+        ('F', 'H') => Unit::Hertz,
+        ('H', 'P') => Unit::Percent,
+        ('I', 'A') => Unit::Amperes,
+        ('P', 'B') => Unit::Bars,
+        ('P', 'P') => Unit::Pascals,
+        ('T', 'R') => Unit::RevolutionsPerMinute,
+        ('U', 'V') => Unit::Volts,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nmea0183::Start;
+
+    fn sentence(formatter: &str, fields: &str) -> Sentence {
+        Sentence {
+            start: Start::Parametric,
+            talker: "II".to_string(),
+            formatter: formatter.to_string(),
+            fields: fields.split(',').map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn wrong_sentence() {
+        let hdt = sentence("HDT", "274.07,T");
+
+        assert_eq!(Hdg::from_sentence(&hdt), Err(Nmea0183Error::WrongSentence));
+        assert_eq!(Xdr::from_sentence(&hdt), Err(Nmea0183Error::WrongSentence));
+    }
+
+    #[test]
+    fn hdg_variation() {
+        let hdg = Hdg::from_sentence(&sentence("HDG", "98.3,0.0,E,12.6,W")).unwrap();
+        assert_eq!(hdg.deviation, Some(0.0));
+        assert_eq!(hdg.variation, Some(-12.6));
+
+        assert_eq!(
+            Hdg::from_sentence(&sentence("HDG", "98.3,,,12.6,X")),
+            Err(Nmea0183Error::Field(4))
+        );
+        assert_eq!(
+            Hdg::from_sentence(&sentence("HDG", "98.3,1.0,,,")),
+            Err(Nmea0183Error::Field(2))
+        );
+    }
+
+    #[test]
+    fn mwv_reference_and_unit() {
+        let mwv = Mwv::from_sentence(&sentence("MWV", "214.8,T,0.1,K,A")).unwrap();
+        assert_eq!(mwv.reference, WindReference::Theoretical);
+        assert_eq!(mwv.speed_unit, Some(Unit::KilometersPerHour));
+
+        let mwv = Mwv::from_sentence(&sentence("MWV", ",R,,,V")).unwrap();
+        assert_eq!(mwv.speed_unit, None);
+        assert!(!mwv.valid);
+
+        assert_eq!(
+            Mwv::from_sentence(&sentence("MWV", "214.8,,0.1,N,A")),
+            Err(Nmea0183Error::Field(1))
+        );
+        assert_eq!(
+            Mwv::from_sentence(&sentence("MWV", "214.8,R,0.1,S,A")),
+            Err(Nmea0183Error::Field(3))
+        );
+        assert_eq!(
+            Mwv::from_sentence(&sentence("MWV", "214.8,R,0.1,N,")),
+            Err(Nmea0183Error::Field(4))
+        );
+    }
+
+    #[test]
+    fn mtw_unit() {
+        assert_eq!(
+            Mtw::from_sentence(&sentence("MTW", "17.9,"))
+                .unwrap()
+                .temperature,
+            Some(17.9)
+        );
+        assert_eq!(
+            Mtw::from_sentence(&sentence("MTW", "64.2,F")),
+            Err(Nmea0183Error::Field(1))
+        );
+    }
+
+    #[test]
+    fn rsa_status() {
+        let rsa = Rsa::from_sentence(&sentence("RSA", "-3.5,A,,V")).unwrap();
+        assert_eq!(rsa.starboard, Some(-3.5));
+        assert_eq!(rsa.port, None);
+
+        let rsa = Rsa::from_sentence(&sentence("RSA", "10.0,V,2.0,")).unwrap();
+        assert_eq!(rsa.starboard, None);
+        assert_eq!(rsa.port, None);
+
+        assert_eq!(
+            Rsa::from_sentence(&sentence("RSA", "10.0,X,,")),
+            Err(Nmea0183Error::Field(1))
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            Dbt::from_sentence(&sentence("DBT", "12.3,f,3.7,M,x,F")),
+            Err(Nmea0183Error::Field(4))
+        );
+        assert_eq!(
+            Dpt::from_sentence(&sentence("DPT", "3.7,0.5"))
+                .unwrap()
+                .range,
+            None
+        );
+        assert_eq!(
+            Vhw::from_sentence(&sentence("VHW", ",T,,M,5.5,N,10.2,K"))
+                .unwrap()
+                .speed_knots,
+            Some(5.5)
+        );
+        assert_eq!(
+            Mwd::from_sentence(&sentence("MWD", "270,T,,M,12.4,N,6.4,M")),
+            Ok(Mwd {
+                direction_true: Some(270.0),
+                direction_magnetic: None,
+                speed_knots: Some(12.4),
+                speed_mps: Some(6.4),
+            })
+        );
+    }
+
+    #[test]
+    fn xdr_groups() {
+        let xdr = Xdr::from_sentence(&sentence("XDR", "C,19.5,C,AIR,X,1,Q,CUSTOM,P,,B,")).unwrap();
+
+        assert_eq!(xdr.transducers.len(), 3);
+        assert_eq!(xdr.transducers[0].unit, Some(Unit::DegreesCelcius));
+        assert_eq!(xdr.transducers[1].unit_code, Some('Q'));
+        assert_eq!(xdr.transducers[1].unit, None);
+        assert_eq!(xdr.transducers[2].value, None);
+        assert_eq!(xdr.transducers[2].name, "");
+
+        assert_eq!(
+            Xdr::from_sentence(&sentence("XDR", "C,19.5,C")),
+            Err(Nmea0183Error::Field(3))
+        );
+        assert_eq!(
+            Xdr::from_sentence(&sentence("XDR", ",19.5,C,AIR")),
+            Err(Nmea0183Error::Field(0))
+        );
+        assert_eq!(
+            Xdr::from_sentence(&sentence("XDR", "CC,19.5,C,AIR")),
+            Err(Nmea0183Error::Field(0))
+        );
+    }
+}
@@ -0,0 +1,640 @@
+//! NMEA 0183 sentences.
+//!
+//! Each sentence is a line of printable ASCII:
+//!
+//! ```text
+//! $GPGLL,4916.45,N,12311.12,W,225444,A*31
+//! ```
+//!
+//! That is a start character, `$` for most sentences or `!` for those which encapsulate another
+//! encoding such as AIS, then the address and comma separated fields, then `*` and a checksum in
+//! hex. The address is a two character talker ID, naming the kind of device that sent it, and a
+//! three character sentence formatter. Proprietary sentences have the talker ID `P` followed by a
+//! manufacturer code and whatever formatter the manufacturer chose.
+//!
+//! [Sentence::decode](struct.Sentence.html#method.decode) turns the sentences this crate knows
+//! into typed structs, listed in [Data](enum.Data.html).
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use calendar;
+use hex;
+
+pub mod gnss;
+pub mod instruments;
+pub mod vdm;
+
+pub use self::gnss::{
+    FixQuality, FixType, Gga, Gll, Gsa, Gsv, Mode, Rmc, Satellite, Selection, Vtg, Zda,
+};
+pub use self::instruments::{
+    Dbt, Dpt, Hdg, Hdt, Mtw, Mwd, Mwv, Rsa, Transducer, Vhw, WindReference, Xdr,
+};
+pub use self::vdm::{Vdm, VdmAssembler};
+
+/// Errors which may occur while parsing an NMEA 0183 sentence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Nmea0183Error {
+    /// The sentence does not start with `$` or `!`.
+    Start,
+    /// The sentence contains a character which is not printable ASCII.
+    Character(char),
+    /// The address is not a talker ID and sentence formatter.
+    Address,
+    /// The sentence has no checksum, or its checksum is not two hex digits.
+    MissingChecksum,
+    /// The checksum of the sentence does not match its contents.
+    Checksum {
+        /// Checksum computed from the contents.
+        expected: u8,
+        /// Checksum at the end of the sentence.
+        found: u8,
+    },
+    /// The sentence formatter is not one this crate decodes.
+    UnknownSentence(String),
+    /// The sentence is not the kind being decoded.
+    WrongSentence,
+    /// The field at this index is missing, or its value is malformed or out of range.
+    Field(usize),
+}
+
+impl fmt::Display for Nmea0183Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Nmea0183Error::Start => write!(f, "sentence does not start with '$' or '!'"),
+            Nmea0183Error::Character(c) => write!(f, "invalid character {:?}", c),
+            Nmea0183Error::Address => write!(f, "invalid address"),
+            Nmea0183Error::MissingChecksum => write!(f, "missing checksum"),
+            Nmea0183Error::Checksum { expected, found } => write!(
+                f,
+                "checksum mismatch: expected {:02X}, found {:02X}",
+                expected, found
+            ),
+            Nmea0183Error::UnknownSentence(ref formatter) => {
+                write!(f, "unknown sentence formatter {}", formatter)
+            }
+            Nmea0183Error::WrongSentence => write!(f, "not the expected sentence"),
+            Nmea0183Error::Field(index) => write!(f, "invalid field {}", index),
+        }
+    }
+}
+
+impl Error for Nmea0183Error {}
+
+/// The start character of a sentence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Start {
+    /// `$`, for sentences whose fields are values.
+    Parametric,
+    /// `!`, for sentences whose fields encapsulate another encoding, such as AIS.
+    Encapsulation,
+}
+
+/// An NMEA 0183 sentence split into its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sentence {
+    /// The start character.
+    pub start: Start,
+    /// The talker ID, such as `GP`, or `P` for proprietary sentences.
+    pub talker: String,
+    /// The sentence formatter, such as `GGA`. For proprietary sentences this is the rest of the
+    /// address, starting with the manufacturer code.
+    pub formatter: String,
+    /// The fields after the address. Empty fields are empty strings.
+    pub fields: Vec<String>,
+}
+
+impl Sentence {
+    /// Parses a sentence, checking its checksum. Leading and trailing whitespace, such as the
+    /// `\r\n` ending each line, is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::nmea0183::{Sentence, Start};
+    ///
+    /// let line = "$GPGLL,4916.45,N,12311.12,W,225444,A*31";
+    /// let sentence = Sentence::parse(line).unwrap();
+    ///
+    /// assert_eq!(sentence.start, Start::Parametric);
+    /// assert_eq!(sentence.talker, "GP");
+    /// assert_eq!(sentence.formatter, "GLL");
+    /// assert_eq!(sentence.fields[1], "N");
+    /// assert_eq!(sentence.to_string(), line);
+    /// ```
+    ///
+    /// A sentence which has been corrupted is reported rather than parsed:
+    ///
+    /// ```
+    /// use libnmea::nmea0183::{Nmea0183Error, Sentence};
+    ///
+    /// let result = Sentence::parse("$GPGLL,4916.45,N,12311.12,E,225444,A*31");
+    ///
+    /// assert_eq!(
+    ///     result,
+    ///     Err(Nmea0183Error::Checksum { expected: 0x23, found: 0x31 })
+    /// );
+    /// assert_eq!(
+    ///     Sentence::parse("$GPGLL,4916.45,N,12311.12,E,225444,A*+1"),
+    ///     Err(Nmea0183Error::MissingChecksum)
+    /// );
+    /// ```
+    pub fn parse(line: &str) -> Result<Sentence, Nmea0183Error> {
+        let line = line.trim();
+        let start = match line.chars().next() {
+            Some('$') => Start::Parametric,
+            Some('!') => Start::Encapsulation,
+            _ => return Err(Nmea0183Error::Start),
+        };
+        if let Some(c) = line.chars().find(|c| !(' '..='~').contains(c)) {
+            return Err(Nmea0183Error::Character(c));
+        }
+
+        let star = line.rfind('*').ok_or(Nmea0183Error::MissingChecksum)?;
+        let (body, found) = (&line[1..star], &line[star + 1..]);
+        if found.len() != 2 || !hex::is_hex(found) {
+            return Err(Nmea0183Error::MissingChecksum);
+        }
+        let found = u8::from_str_radix(found, 16).map_err(|_| Nmea0183Error::MissingChecksum)?;
+        let expected = checksum(body);
+        if found != expected {
+            return Err(Nmea0183Error::Checksum { expected, found });
+        }
+
+        let mut fields = body.split(',');
+        let address = fields.next().unwrap_or("");
+        if !address.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(Nmea0183Error::Address);
+        }
+        let (talker, formatter) = if address.starts_with('P') && address.len() > 1 {
+            address.split_at(1)
+        } else if address.len() == 5 {
+            address.split_at(2)
+        } else {
+            return Err(Nmea0183Error::Address);
+        };
+
+        Ok(Sentence {
+            start,
+            talker: talker.to_string(),
+            formatter: formatter.to_string(),
+            fields: fields.map(|f| f.to_string()).collect(),
+        })
+    }
+
+    /// The talker ID and sentence formatter together, such as `GPGGA`.
+    pub fn address(&self) -> String {
+        format!("{}{}", self.talker, self.formatter)
+    }
+
+    /// Decodes the fields of the sentence according to its formatter. The talker ID is not
+    /// considered, so `$GPGGA` and `$GNGGA` both decode as [Gga](gnss/struct.Gga.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::nmea0183::{Data, Sentence};
+    ///
+    /// let line = "$GPGLL,4916.45,N,12311.12,W,225444,A*31";
+    /// let data = Sentence::parse(line).unwrap().decode().unwrap();
+    ///
+    /// match data {
+    ///     Data::Gll(gll) => assert_eq!(gll.latitude, Some(49.274166666666666)),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn decode(&self) -> Result<Data, Nmea0183Error> {
+        if self.talker == "P" {
+            return Err(Nmea0183Error::UnknownSentence(self.formatter.clone()));
+        }
+
+        Ok(match self.formatter.as_str() {
+            "DBT" => Data::Dbt(Dbt::from_sentence(self)?),
+            "DPT" => Data::Dpt(Dpt::from_sentence(self)?),
+            "GGA" => Data::Gga(Gga::from_sentence(self)?),
+            "GLL" => Data::Gll(Gll::from_sentence(self)?),
+            "GSA" => Data::Gsa(Gsa::from_sentence(self)?),
+            "GSV" => Data::Gsv(Gsv::from_sentence(self)?),
+            "HDG" => Data::Hdg(Hdg::from_sentence(self)?),
+            "HDT" => Data::Hdt(Hdt::from_sentence(self)?),
+            "MTW" => Data::Mtw(Mtw::from_sentence(self)?),
+            "MWD" => Data::Mwd(Mwd::from_sentence(self)?),
+            "MWV" => Data::Mwv(Mwv::from_sentence(self)?),
+            "RMC" => Data::Rmc(Rmc::from_sentence(self)?),
+            "RSA" => Data::Rsa(Rsa::from_sentence(self)?),
+            "VDM" | "VDO" => Data::Vdm(Vdm::from_sentence(self)?),
+            "VHW" => Data::Vhw(Vhw::from_sentence(self)?),
+            "VTG" => Data::Vtg(Vtg::from_sentence(self)?),
+            "XDR" => Data::Xdr(Xdr::from_sentence(self)?),
+            "ZDA" => Data::Zda(Zda::from_sentence(self)?),
+            _ => return Err(Nmea0183Error::UnknownSentence(self.formatter.clone())),
+        })
+    }
+
+    /// Checks the sentence has the given formatter.
+    fn expect(&self, formatter: &str) -> Result<(), Nmea0183Error> {
+        if self.talker == "P" || self.formatter != formatter {
+            return Err(Nmea0183Error::WrongSentence);
+        }
+
+        Ok(())
+    }
+
+    /// The field at an index, or `None` if it is missing or empty.
+    fn field(&self, index: usize) -> Option<&str> {
+        match self.fields.get(index) {
+            Some(field) if !field.is_empty() => Some(field),
+            _ => None,
+        }
+    }
+
+    /// The field at an index parsed as a number, or `None` if it is missing or empty.
+    fn number<T: FromStr>(&self, index: usize) -> Result<Option<T>, Nmea0183Error> {
+        match self.field(index) {
+            Some(field) => field
+                .parse()
+                .map(Some)
+                .map_err(|_| Nmea0183Error::Field(index)),
+            None => Ok(None),
+        }
+    }
+
+    /// The single character field at an index, or `None` if it is missing or empty.
+    fn character(&self, index: usize) -> Result<Option<char>, Nmea0183Error> {
+        match self.field(index) {
+            Some(field) if field.len() == 1 => Ok(field.chars().next()),
+            Some(_) => Err(Nmea0183Error::Field(index)),
+            None => Ok(None),
+        }
+    }
+
+    /// The `A` (valid) or `V` (void) status field at an index.
+    fn status(&self, index: usize) -> Result<bool, Nmea0183Error> {
+        match self.character(index)? {
+            Some('A') => Ok(true),
+            Some('V') => Ok(false),
+            _ => Err(Nmea0183Error::Field(index)),
+        }
+    }
+
+    /// A time of day written as `hhmmss.ss` at an index.
+    fn time(&self, index: usize) -> Result<Option<Duration>, Nmea0183Error> {
+        let field = match self.field(index) {
+            Some(field) => field,
+            None => return Ok(None),
+        };
+        parse_time(field)
+            .map(Some)
+            .ok_or(Nmea0183Error::Field(index))
+    }
+
+    /// A latitude written as `ddmm.mm` at an index followed by `N` or `S`, in degrees north.
+    fn latitude(&self, index: usize) -> Result<Option<f64>, Nmea0183Error> {
+        self.coordinate(index, 90.0, 'N', 'S')
+    }
+
+    /// A longitude written as `dddmm.mm` at an index followed by `E` or `W`, in degrees east.
+    fn longitude(&self, index: usize) -> Result<Option<f64>, Nmea0183Error> {
+        self.coordinate(index, 180.0, 'E', 'W')
+    }
+
+    fn coordinate(
+        &self,
+        index: usize,
+        limit: f64,
+        positive: char,
+        negative: char,
+    ) -> Result<Option<f64>, Nmea0183Error> {
+        let field = match self.field(index) {
+            Some(field) => field,
+            None => return Ok(None),
+        };
+
+        // The minutes are the two digits before the decimal point and any after it.
+        let split = field.find('.').unwrap_or(field.len());
+        if split < 2 || !field.is_char_boundary(split - 2) {
+            return Err(Nmea0183Error::Field(index));
+        }
+        let (degrees, minutes) = field.split_at(split - 2);
+        let degrees: f64 = if degrees.is_empty() {
+            0.0
+        } else {
+            degrees.parse().map_err(|_| Nmea0183Error::Field(index))?
+        };
+        let minutes: f64 = minutes.parse().map_err(|_| Nmea0183Error::Field(index))?;
+        let value = degrees + minutes / 60.0;
+        if !(0.0..=limit).contains(&value) || !(0.0..60.0).contains(&minutes) {
+            return Err(Nmea0183Error::Field(index));
+        }
+
+        match self.character(index + 1)? {
+            Some(c) if c == positive => Ok(Some(value)),
+            Some(c) if c == negative => Ok(Some(-value)),
+            _ => Err(Nmea0183Error::Field(index + 1)),
+        }
+    }
+
+    /// A value at an index followed by `E` or `W`, made negative when west.
+    fn variation(&self, index: usize) -> Result<Option<f64>, Nmea0183Error> {
+        let value: f64 = match self.number(index)? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        match self.character(index + 1)? {
+            Some('E') => Ok(Some(value)),
+            Some('W') => Ok(Some(-value)),
+            _ => Err(Nmea0183Error::Field(index + 1)),
+        }
+    }
+}
+
+impl fmt::Display for Sentence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut body = self.address();
+        for field in &self.fields {
+            body.push(',');
+            body.push_str(field);
+        }
+        let start = match self.start {
+            Start::Parametric => '$',
+            Start::Encapsulation => '!',
+        };
+
+        write!(f, "{}{}*{:02X}", start, body, checksum(&body))
+    }
+}
+
+/// A sentence decoded by [Sentence::decode](struct.Sentence.html#method.decode).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Data {
+    /// Depth below transducer.
+    Dbt(Dbt),
+    /// Depth.
+    Dpt(Dpt),
+    /// Global Positioning System fix data.
+    Gga(Gga),
+    /// Geographic position.
+    Gll(Gll),
+    /// GNSS DOP and active satellites.
+    Gsa(Gsa),
+    /// GNSS satellites in view.
+    Gsv(Gsv),
+    /// Heading, deviation and variation.
+    Hdg(Hdg),
+    /// Heading, true.
+    Hdt(Hdt),
+    /// Water temperature.
+    Mtw(Mtw),
+    /// Wind direction and speed.
+    Mwd(Mwd),
+    /// Wind speed and angle.
+    Mwv(Mwv),
+    /// Recommended minimum specific GNSS data.
+    Rmc(Rmc),
+    /// Rudder sensor angle.
+    Rsa(Rsa),
+    /// AIS message, or a fragment of one.
+    Vdm(Vdm),
+    /// Water speed and heading.
+    Vhw(Vhw),
+    /// Course over ground and ground speed.
+    Vtg(Vtg),
+    /// Transducer measurements.
+    Xdr(Xdr),
+    /// Time and date.
+    Zda(Zda),
+}
+
+/// A calendar date, as sent by RMC and ZDA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    /// Year, such as 2018.
+    pub year: i64,
+    /// Month, from 1 to 12.
+    pub month: u32,
+    /// Day of the month, from 1.
+    pub day: u32,
+}
+
+impl Date {
+    /// Combines the date with a time of day into the time since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use libnmea::nmea0183::Date;
+    ///
+    /// let date = Date { year: 2018, month: 4, day: 23 };
+    ///
+    /// assert_eq!(
+    ///     date.with_time(Duration::from_secs(3600)),
+    ///     Some(Duration::from_secs(1524445200))
+    /// );
+    /// ```
+    pub fn with_time(&self, time_of_day: Duration) -> Option<Duration> {
+        calendar::to_epoch(self.year, self.month, self.day, time_of_day)
+    }
+}
+
+/// Parses a time of day written as `hhmmss` with an optional fraction.
+fn parse_time(text: &str) -> Option<Duration> {
+    let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if text.len() < 6 || !text.is_char_boundary(6) || !digits(&text[..6]) {
+        return None;
+    }
+    let fraction = match text[6..].strip_prefix('.') {
+        Some(fraction) => fraction,
+        None if text.len() == 6 => "0",
+        None => return None,
+    };
+    if fraction.is_empty() || !digits(fraction) {
+        return None;
+    }
+
+    let hours: u64 = text[..2].parse().ok()?;
+    let minutes: u64 = text[2..4].parse().ok()?;
+    let seconds: f64 = text[4..].parse().ok()?;
+    if hours > 23 || minutes > 59 || !(0.0..61.0).contains(&seconds) {
+        return None;
+    }
+
+    let millis = (seconds * 1000.0).round() as u64;
+    Some(Duration::from_millis(
+        (hours * 60 + minutes) * 60_000 + millis,
+    ))
+}
+
+/// Computes the checksum of a sentence: the exclusive or of every byte between the start
+/// character and the `*`.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::nmea0183::checksum;
+///
+/// assert_eq!(checksum("GPGLL,4916.45,N,12311.12,W,225444,A"), 0x31);
+/// ```
+pub fn checksum(body: &str) -> u8 {
+    body.bytes().fold(0, |sum, b| sum ^ b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A sentence with the given address and fields, and a valid checksum.
+    fn sentence(address: &str, fields: &[&str]) -> Sentence {
+        let line = format!("${},{}*00", address, fields.join(","));
+        let body = &line[1..line.len() - 3];
+        let line = format!("${}*{:02X}", body, checksum(body));
+        Sentence::parse(&line).unwrap()
+    }
+
+    #[test]
+    fn proprietary_and_encapsulation() {
+        let sentence = Sentence::parse("$PGRME,15.0,M,45.0,M,25.0,M*1C").unwrap();
+        assert_eq!(sentence.talker, "P");
+        assert_eq!(sentence.formatter, "GRME");
+        assert_eq!(
+            sentence.decode(),
+            Err(Nmea0183Error::UnknownSentence("GRME".to_string()))
+        );
+
+        let sentence = Sentence::parse("!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26").unwrap();
+        assert_eq!(sentence.start, Start::Encapsulation);
+        assert_eq!(sentence.address(), "AIVDM");
+    }
+
+    #[test]
+    fn lowercase_checksum_and_line_ending() {
+        let sentence = Sentence::parse("$GPHDT,274.07,T*03\r\n").unwrap();
+        assert_eq!(sentence.fields, vec!["274.07", "T"]);
+
+        let sentence = Sentence::parse("$GPHDT,274.08,T*0c").unwrap();
+        assert_eq!(sentence.fields, vec!["274.08", "T"]);
+    }
+
+    #[test]
+    fn rejected_sentences() {
+        let cases = [
+            ("", Nmea0183Error::Start),
+            ("GPHDT,274.07,T*03", Nmea0183Error::Start),
+            ("$GPHDT,274.07,T\t*03", Nmea0183Error::Character('\t')),
+            (
+                "$GPHDT,274.07,\u{b0}T*03",
+                Nmea0183Error::Character('\u{b0}'),
+            ),
+            ("$GPHDT,274.07,T", Nmea0183Error::MissingChecksum),
+            ("$GPHDT,274.07,T*3", Nmea0183Error::MissingChecksum),
+            ("$GPHDT,274.07,T*003", Nmea0183Error::MissingChecksum),
+            ("$GPHDT,274.07,T*0G", Nmea0183Error::MissingChecksum),
+            (
+                "$GPHDT,274.07,T*04",
+                Nmea0183Error::Checksum {
+                    expected: 0x03,
+                    found: 0x04,
+                },
+            ),
+            ("$GPHD,274.07,T*57", Nmea0183Error::Address),
+            ("$GPHDTX,274.07,T*5B", Nmea0183Error::Address),
+            ("$GP-DT,274.07,T*66", Nmea0183Error::Address),
+            ("$P,274.07,T*1C", Nmea0183Error::Address),
+            ("$*00", Nmea0183Error::Address),
+        ];
+
+        for &(line, ref error) in &cases {
+            assert_eq!(Sentence::parse(line).as_ref(), Err(error), "{}", line);
+        }
+    }
+
+    #[test]
+    fn unknown_and_wrong_sentences() {
+        assert_eq!(
+            sentence("GPXYZ", &["1"]).decode(),
+            Err(Nmea0183Error::UnknownSentence("XYZ".to_string()))
+        );
+        assert_eq!(
+            sentence("GPHDT", &["1", "T"]).expect("HDG"),
+            Err(Nmea0183Error::WrongSentence)
+        );
+        assert_eq!(
+            sentence("PHDT", &["1", "T"]).expect("HDT"),
+            Err(Nmea0183Error::WrongSentence)
+        );
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(parse_time("000000"), Some(Duration::from_secs(0)));
+        assert_eq!(
+            parse_time("235959.99"),
+            Some(Duration::from_millis(86_399_990))
+        );
+        assert_eq!(
+            parse_time("123519.5"),
+            Some(Duration::from_millis(45_319_500))
+        );
+
+        for text in &[
+            "12351",
+            "1235199",
+            "123519.",
+            "12351a",
+            "+12351",
+            "123519.+5",
+            "123519.5e1",
+            "240000",
+            "126000",
+            "123561",
+            "12\u{e9}519",
+        ] {
+            assert_eq!(parse_time(text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn coordinates() {
+        let gll = sentence("GPGLL", &["4916.45", "N", "12311.12", "W", "0030.00", "S"]);
+        assert_eq!(gll.latitude(0), Ok(Some(49.274166666666666)));
+        assert_eq!(gll.longitude(2), Ok(Some(-123.18533333333333)));
+        assert_eq!(gll.latitude(4), Ok(Some(-0.5)));
+        assert_eq!(gll.latitude(6), Ok(None));
+
+        for fields in &[
+            ["9100.00", "N"],
+            ["4960.00", "N"],
+            ["4916.45", "E"],
+            ["4916.45", ""],
+            ["4916.45", "NN"],
+            ["6.45", "N"],
+            ["-4916.45", "N"],
+            ["49x6.45", "N"],
+        ] {
+            assert!(
+                sentence("GPGLL", &fields[..]).latitude(0).is_err(),
+                "{:?}",
+                fields
+            );
+        }
+        assert!(sentence("GPGLL", &["18100.00", "E"]).longitude(0).is_err());
+    }
+
+    #[test]
+    fn status_and_variation() {
+        let sentence = sentence("GPRMC", &["A", "V", "X", "", "3.1", "W", "3.1", "N"]);
+
+        assert_eq!(sentence.status(0), Ok(true));
+        assert_eq!(sentence.status(1), Ok(false));
+        assert_eq!(sentence.status(2), Err(Nmea0183Error::Field(2)));
+        assert_eq!(sentence.status(3), Err(Nmea0183Error::Field(3)));
+        assert_eq!(sentence.variation(4), Ok(Some(-3.1)));
+        assert_eq!(sentence.variation(6), Err(Nmea0183Error::Field(7)));
+        assert_eq!(sentence.number::<f64>(2), Err(Nmea0183Error::Field(2)));
+        assert_eq!(sentence.number::<f64>(8), Ok(None));
+    }
+}
@@ -0,0 +1,301 @@
+//! AIS messages carried by `!AIVDM` and `!AIVDO` sentences.
+//!
+//! A sentence holds at most 82 characters, so longer messages are split into fragments sent as
+//! consecutive sentences:
+//!
+//! ```text
+//! !AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C
+//! !AIVDM,2,2,1,A,88888888880,2*25
+//! ```
+//!
+//! That is the number of fragments, the number of this fragment, a sequential message ID shared
+//! by the fragments of one message, the radio channel, the payload and the fill bits.
+//! [VdmAssembler](struct.VdmAssembler.html) joins fragments back into whole messages.
+
+use std::collections::HashMap;
+
+use ais::{self, AisError};
+
+use super::{Nmea0183Error, Sentence};
+
+/// An `!AIVDM` or `!AIVDO` sentence, holding all or part of an AIS message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vdm {
+    /// Whether the message was sent by this vessel (`VDO`) rather than received (`VDM`).
+    pub own: bool,
+    /// Number of fragments the message is split into.
+    pub count: u8,
+    /// Number of this fragment, from 1.
+    pub number: u8,
+    /// Sequential message ID, shared by the fragments of a message split over several.
+    pub sequence: Option<u8>,
+    /// Radio channel the message was received on, `A` or `B`, or `1` or `2` from some receivers.
+    pub channel: Option<char>,
+    /// The armored payload.
+    pub payload: String,
+    /// Number of bits added to the end of the payload to make a whole number of characters.
+    pub fill_bits: u8,
+}
+
+impl Vdm {
+    /// Decodes a VDM or VDO sentence.
+    pub fn from_sentence(sentence: &Sentence) -> Result<Vdm, Nmea0183Error> {
+        let own = match sentence.formatter.as_str() {
+            _ if sentence.talker == "P" => return Err(Nmea0183Error::WrongSentence),
+            "VDM" => false,
+            "VDO" => true,
+            _ => return Err(Nmea0183Error::WrongSentence),
+        };
+
+        let count = sentence.number(0)?.ok_or(Nmea0183Error::Field(0))?;
+        let number = sentence.number(1)?.ok_or(Nmea0183Error::Field(1))?;
+        if count == 0 {
+            return Err(Nmea0183Error::Field(0));
+        }
+        if number == 0 || number > count {
+            return Err(Nmea0183Error::Field(1));
+        }
+
+        Ok(Vdm {
+            own,
+            count,
+            number,
+            sequence: sentence.number(2)?,
+            channel: sentence.character(3)?,
+            payload: sentence.field(4).unwrap_or("").to_string(),
+            fill_bits: sentence.number(5)?.ok_or(Nmea0183Error::Field(5))?,
+        })
+    }
+
+    /// Whether the sentence holds a whole message, rather than a fragment of one.
+    pub fn is_complete(&self) -> bool {
+        self.count == 1
+    }
+
+    /// Decodes the payload of a whole message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::ais::Message;
+    /// use libnmea::nmea0183::{Sentence, Vdm};
+    ///
+    /// let line = "!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C";
+    /// let vdm = Vdm::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+    ///
+    /// assert_eq!(vdm.decode().unwrap().mmsi(), 477553000);
+    /// ```
+    pub fn decode(&self) -> Result<ais::Message, AisError> {
+        ais::decode(&self.payload, self.fill_bits)
+    }
+}
+
+/// Joins the fragments of AIS messages.
+///
+/// Fragments are matched by their sequential message ID and channel, and whether they are VDM or
+/// VDO, so messages sent on both channels at once are assembled separately. Each message's
+/// fragments must arrive in order: a fragment which does not follow on from the last one with
+/// the same key drops the message it would have been part of.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::ais::Message;
+/// use libnmea::nmea0183::{Sentence, Vdm, VdmAssembler};
+///
+/// let lines = [
+///     "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+///     "!AIVDM,2,2,1,A,88888888880,2*25",
+/// ];
+///
+/// let mut assembler = VdmAssembler::new();
+/// let mut complete = None;
+/// for line in lines.iter() {
+///     let vdm = Vdm::from_sentence(&Sentence::parse(line).unwrap()).unwrap();
+///     complete = assembler.push(vdm);
+/// }
+///
+/// match complete.unwrap().decode().unwrap() {
+///     Message::StaticAndVoyageData(data) => {
+///         assert_eq!(data.mmsi, 351759000);
+///         assert_eq!(data.name, "EVER DIADEM");
+///         assert_eq!(data.destination, "NEW YORK");
+///         assert_eq!(data.draught, Some(12.2));
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct VdmAssembler {
+    pending: HashMap<(bool, Option<u8>, Option<char>), Vdm>,
+}
+
+impl VdmAssembler {
+    /// Creates an assembler with no fragments pending.
+    pub fn new() -> VdmAssembler {
+        VdmAssembler::default()
+    }
+
+    /// Adds a sentence. Returns the whole message when the sentence completes one, as a single
+    /// sentence with the payloads of all the fragments.
+    pub fn push(&mut self, fragment: Vdm) -> Option<Vdm> {
+        if fragment.is_complete() {
+            return Some(fragment);
+        }
+
+        let key = (fragment.own, fragment.sequence, fragment.channel);
+        if fragment.number == 1 {
+            self.pending.insert(key, fragment);
+            return None;
+        }
+
+        let mut message = self.pending.remove(&key)?;
+        if message.count != fragment.count || message.number + 1 != fragment.number {
+            return None;
+        }
+
+        message.payload.push_str(&fragment.payload);
+        message.number = fragment.number;
+        message.fill_bits = fragment.fill_bits;
+        if message.number < message.count {
+            self.pending.insert(key, message);
+            return None;
+        }
+
+        message.count = 1;
+        message.number = 1;
+        Some(message)
+    }
+
+    /// Number of messages with fragments still to come.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nmea0183::Start;
+
+    fn sentence(talker: &str, formatter: &str, fields: &str) -> Sentence {
+        Sentence {
+            start: Start::Encapsulation,
+            talker: talker.to_string(),
+            formatter: formatter.to_string(),
+            fields: fields.split(',').map(String::from).collect(),
+        }
+    }
+
+    fn vdm(fields: &str) -> Result<Vdm, Nmea0183Error> {
+        Vdm::from_sentence(&sentence("AI", "VDM", fields))
+    }
+
+    fn fragment(count: u8, number: u8, sequence: u8, channel: char, payload: &str) -> Vdm {
+        Vdm {
+            own: false,
+            count,
+            number,
+            sequence: Some(sequence),
+            channel: Some(channel),
+            payload: payload.to_string(),
+            fill_bits: 0,
+        }
+    }
+
+    #[test]
+    fn wrong_sentence() {
+        let vdo = Vdm::from_sentence(&sentence("AI", "VDO", "1,1,,,,0")).unwrap();
+        assert!(vdo.own);
+        assert_eq!(vdo.sequence, None);
+        assert_eq!(vdo.channel, None);
+
+        assert_eq!(
+            Vdm::from_sentence(&sentence("AI", "VDR", "1,1,,A,,0")),
+            Err(Nmea0183Error::WrongSentence)
+        );
+        assert_eq!(
+            Vdm::from_sentence(&sentence("P", "VDM", "1,1,,A,,0")),
+            Err(Nmea0183Error::WrongSentence)
+        );
+    }
+
+    #[test]
+    fn rejected_fields() {
+        assert_eq!(vdm(",1,,A,1,0"), Err(Nmea0183Error::Field(0)));
+        assert_eq!(vdm("0,0,,A,1,0"), Err(Nmea0183Error::Field(0)));
+        assert_eq!(vdm("x,1,,A,1,0"), Err(Nmea0183Error::Field(0)));
+        assert_eq!(vdm("2,,,A,1,0"), Err(Nmea0183Error::Field(1)));
+        assert_eq!(vdm("2,0,,A,1,0"), Err(Nmea0183Error::Field(1)));
+        assert_eq!(vdm("2,3,,A,1,0"), Err(Nmea0183Error::Field(1)));
+        assert_eq!(vdm("2,1,x,A,1,0"), Err(Nmea0183Error::Field(2)));
+        assert_eq!(vdm("2,1,1,AB,1,0"), Err(Nmea0183Error::Field(3)));
+        assert_eq!(vdm("1,1,,A,1,"), Err(Nmea0183Error::Field(5)));
+        assert_eq!(vdm("1,1,,A,1"), Err(Nmea0183Error::Field(5)));
+    }
+
+    #[test]
+    fn out_of_order_fragments() {
+        let mut assembler = VdmAssembler::new();
+
+        assert_eq!(assembler.push(fragment(3, 1, 1, 'A', "1")), None);
+        assert_eq!(assembler.push(fragment(3, 3, 1, 'A', "3")), None);
+        assert_eq!(assembler.pending(), 0);
+        assert_eq!(assembler.push(fragment(3, 2, 1, 'A', "2")), None);
+
+        assert_eq!(assembler.push(fragment(2, 2, 2, 'A', "2")), None);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn mismatched_count() {
+        let mut assembler = VdmAssembler::new();
+
+        assert_eq!(assembler.push(fragment(2, 1, 1, 'A', "1")), None);
+        assert_eq!(assembler.push(fragment(3, 2, 1, 'A', "2")), None);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn new_first_fragment_restarts_message() {
+        let mut assembler = VdmAssembler::new();
+
+        assert_eq!(assembler.push(fragment(2, 1, 1, 'A', "old")), None);
+        assert_eq!(assembler.push(fragment(2, 1, 1, 'A', "new")), None);
+
+        let message = assembler.push(fragment(2, 2, 1, 'A', "2")).unwrap();
+        assert_eq!(message.payload, "new2");
+    }
+
+    #[test]
+    fn interleaved_channels() {
+        let mut assembler = VdmAssembler::new();
+
+        assert_eq!(assembler.push(fragment(3, 1, 1, 'A', "a1")), None);
+        assert_eq!(assembler.push(fragment(2, 1, 1, 'B', "b1")), None);
+        assert_eq!(assembler.push(fragment(2, 1, 2, 'A', "c1")), None);
+        assert_eq!(
+            assembler
+                .push(fragment(1, 1, 1, 'A', "whole"))
+                .unwrap()
+                .payload,
+            "whole"
+        );
+        assert_eq!(assembler.push(fragment(3, 2, 1, 'A', "a2")), None);
+        assert_eq!(assembler.pending(), 3);
+
+        let b = assembler.push(fragment(2, 2, 1, 'B', "b2")).unwrap();
+        assert_eq!(b.payload, "b1b2");
+        assert_eq!(b.channel, Some('B'));
+
+        let mut a = fragment(3, 3, 1, 'A', "a3");
+        a.fill_bits = 4;
+        let a = assembler.push(a).unwrap();
+        assert_eq!((a.count, a.number), (1, 1));
+        assert_eq!(a.payload, "a1a2a3");
+        assert_eq!(a.fill_bits, 4);
+        assert!(a.is_complete());
+
+        assert_eq!(assembler.pending(), 1);
+    }
+}
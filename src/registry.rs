@@ -0,0 +1,120 @@
+//! Fast lookup of PGN definitions.
+//!
+//! The definitions from [pgn_list](../fn.pgn_list.html) are indexed by PGN the first time they
+//! are needed, and every lookup after that is a hash map access which allocates nothing.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use bits::BitReader;
+use {pgn_list, Pgn};
+
+/// PGN of the definition used for proprietary PGNs which have no definition of their own.
+const PROPRIETARY_FALLBACK: u32 = 0;
+
+/// PGN definitions indexed by PGN.
+#[derive(Debug)]
+pub struct Registry {
+    pgns: HashMap<u32, Vec<Pgn>>,
+}
+
+impl Registry {
+    /// Builds a registry from a list of definitions. Definitions with fields which must match
+    /// are tried before those without when resolving a payload, otherwise the order of the list
+    /// is kept.
+    pub fn new(list: Vec<Pgn>) -> Registry {
+        let mut pgns: HashMap<u32, Vec<Pgn>> = HashMap::new();
+        for pgn in list {
+            pgns.entry(pgn.pgn).or_default().push(pgn);
+        }
+
+        for definitions in pgns.values_mut() {
+            definitions.sort_by_key(|p| !p.fields.iter().any(|f| f.match_value.is_some()));
+        }
+
+        Registry { pgns }
+    }
+
+    /// Returns every definition of a PGN. Proprietary PGNs without a definition of their own get
+    /// the generic definition, which describes only the manufacturer and industry codes they all
+    /// start with. Any other PGN without a definition gets an empty slice.
+    pub fn get(&self, pgn: u32) -> &[Pgn] {
+        match self.pgns.get(&pgn) {
+            Some(definitions) => definitions,
+            None if is_proprietary(pgn) => self
+                .pgns
+                .get(&PROPRIETARY_FALLBACK)
+                .map_or(&[], |d| d.as_slice()),
+            None => &[],
+        }
+    }
+
+    /// Returns the definition of a PGN which applies to the given payload. When a PGN has more
+    /// than one definition, the first one whose match fields all hold their match values is
+    /// used.
+    pub fn resolve(&self, pgn: u32, data: &[u8]) -> Option<&Pgn> {
+        let data = BitReader::new(data);
+        self.get(pgn).iter().find(|definition| {
+            definition
+                .fields
+                .iter()
+                .all(|field| match field.match_value {
+                    Some(value) => {
+                        data.read_unsigned(field.start as usize, field.size as usize) == Some(value)
+                    }
+                    None => true,
+                })
+        })
+    }
+
+    /// Iterates over every definition in the registry.
+    pub fn iter(&self) -> impl Iterator<Item = &Pgn> {
+        self.pgns.values().flat_map(|d| d.iter())
+    }
+}
+
+/// Returns the registry of every PGN in [pgn_list](../fn.pgn_list.html). It is built on the
+/// first call and shared after that.
+pub fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(|| Registry::new(pgn_list()))
+}
+
+/// Returns every definition of a PGN from the shared [registry](fn.registry.html).
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::registry;
+///
+/// assert_eq!(registry::get(59904)[0].name, "ISO Request");
+/// assert!(registry::get(1).is_empty());
+/// ```
+pub fn get(pgn: u32) -> &'static [Pgn] {
+    registry().get(pgn)
+}
+
+/// Returns the definition of a PGN which applies to the given payload from the shared
+/// [registry](fn.registry.html).
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::registry;
+///
+/// let bam = [32, 9, 0, 2, 0xff, 0x00, 0xee, 0x01];
+/// let definition = registry::resolve(60416, &bam).unwrap();
+///
+/// assert!(definition.name.ends_with("Broadcast Announce"));
+/// ```
+pub fn resolve(pgn: u32, data: &[u8]) -> Option<&'static Pgn> {
+    registry().resolve(pgn, data)
+}
+
+/// Whether a PGN is in one of the ranges set aside for manufacturers' own use.
+pub fn is_proprietary(pgn: u32) -> bool {
+    pgn == 61184
+        || (65280..=65535).contains(&pgn)
+        || pgn == 126720
+        || (130816..=131071).contains(&pgn)
+}
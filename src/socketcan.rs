@@ -0,0 +1,178 @@
+//! Live CAN interfaces through Linux SocketCAN.
+//!
+//! Any interface SocketCAN supports may be used, such as an MCP2515 on SPI, a USB adapter, or a
+//! `vcan` virtual interface for testing:
+//!
+//! ```text
+//! ip link add dev vcan0 type vcan
+//! ip link set up vcan0
+//! ```
+
+use std::ffi::CString;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::os::raw::{c_char, c_int, c_uint};
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+
+use can::{CanId, Frame};
+
+const AF_CAN: c_int = 29;
+const SOCK_RAW: c_int = 3;
+const CAN_RAW: c_int = 1;
+
+const CAN_EFF_FLAG: u32 = 0x8000_0000;
+const CAN_RTR_FLAG: u32 = 0x4000_0000;
+const CAN_ERR_FLAG: u32 = 0x2000_0000;
+
+/// `struct sockaddr_can` from `linux/can.h`. The address union is only used by the transport
+/// protocols SocketCAN implements itself, so it is left as padding.
+#[repr(C)]
+struct SockaddrCan {
+    can_family: u16,
+    can_ifindex: c_int,
+    can_addr: [u64; 2],
+}
+
+/// `struct can_frame` from `linux/can.h`.
+#[repr(C)]
+#[derive(Default)]
+struct CanFrame {
+    can_id: u32,
+    can_dlc: u8,
+    pad: u8,
+    res0: u8,
+    len8_dlc: u8,
+    data: [u8; 8],
+}
+
+extern "C" {
+    fn socket(domain: c_int, kind: c_int, protocol: c_int) -> c_int;
+    fn bind(fd: c_int, address: *const SockaddrCan, length: c_uint) -> c_int;
+    fn if_nametoindex(name: *const c_char) -> c_uint;
+}
+
+/// A raw SocketCAN socket bound to one interface.
+///
+/// # Examples
+///
+/// ```no_run
+/// use libnmea::socketcan::CanSocket;
+///
+/// let mut socket = CanSocket::open("can0").unwrap();
+/// loop {
+///     let frame = socket.read_frame().unwrap();
+///     println!("{} {:?}", frame.id, frame.data());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CanSocket {
+    file: File,
+}
+
+impl CanSocket {
+    /// Opens a socket on the named interface, such as `can0` or `vcan0`.
+    pub fn open(interface: &str) -> io::Result<CanSocket> {
+        let name = CString::new(interface)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "invalid interface name"))?;
+
+        let index = unsafe { if_nametoindex(name.as_ptr()) };
+        if index == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let fd = unsafe { socket(AF_CAN, SOCK_RAW, CAN_RAW) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // Owning the descriptor as a file closes it on every path from here on.
+        let file = unsafe { File::from_raw_fd(fd) };
+
+        let address = SockaddrCan {
+            can_family: AF_CAN as u16,
+            can_ifindex: index as c_int,
+            can_addr: [0; 2],
+        };
+        let result = unsafe { bind(fd, &address, mem::size_of::<SockaddrCan>() as c_uint) };
+        if result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(CanSocket { file })
+    }
+
+    /// Blocks until an extended data frame is received and returns it. Standard, remote and error
+    /// frames are skipped, as NMEA 2000 does not use them.
+    pub fn read_frame(&mut self) -> io::Result<Frame> {
+        loop {
+            let mut raw = CanFrame::default();
+            let size = mem::size_of::<CanFrame>();
+            let read = {
+                let buffer = unsafe {
+                    ::std::slice::from_raw_parts_mut(&mut raw as *mut _ as *mut u8, size)
+                };
+                self.file.read(buffer)?
+            };
+            if read != size {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "short CAN frame",
+                ));
+            }
+
+            if raw.can_id & CAN_EFF_FLAG == 0 || raw.can_id & (CAN_RTR_FLAG | CAN_ERR_FLAG) != 0 {
+                continue;
+            }
+
+            let len = (raw.can_dlc as usize).min(8);
+            if let Some(frame) = Frame::new(CanId::from_raw(raw.can_id), &raw.data[..len]) {
+                return Ok(frame);
+            }
+        }
+    }
+
+    /// Sends a frame as an extended data frame.
+    pub fn write_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let data = frame.data();
+        let mut raw = CanFrame {
+            can_id: frame.id.raw() | CAN_EFF_FLAG,
+            can_dlc: data.len() as u8,
+            ..Default::default()
+        };
+        raw.data[..data.len()].copy_from_slice(data);
+
+        let size = mem::size_of::<CanFrame>();
+        let buffer = unsafe { ::std::slice::from_raw_parts(&raw as *const _ as *const u8, size) };
+        let written = self.file.write(buffer)?;
+        if written != size {
+            return Err(io::Error::new(io::ErrorKind::WriteZero, "short CAN frame"));
+        }
+
+        Ok(())
+    }
+}
+
+impl AsRawFd for CanSocket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.file.as_raw_fd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kernel_struct_sizes() {
+        assert_eq!(mem::size_of::<CanFrame>(), 16);
+        assert_eq!(mem::size_of::<SockaddrCan>(), 24);
+    }
+
+    #[test]
+    fn invalid_interfaces() {
+        let error = CanSocket::open("can\0").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        assert!(CanSocket::open("nonexistent0").is_err());
+    }
+}
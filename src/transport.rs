@@ -0,0 +1,942 @@
+//! ISO 11783 / J1939 transport protocol.
+//!
+//! Messages which are too large for Fast Packet are split into 7-byte packets and sent with
+//! TP.DT (60160) frames, announced and controlled by TP.CM (60416) frames. A message may be
+//! broadcast, in which case it is announced with a BAM and the packets follow at the sender's
+//! pace, or sent to a single device, in which case the sender asks with an RTS, the receiver
+//! grants packets with CTS, and acknowledges the complete message with an EndOfMsgAck. Either side
+//! of a connection may give up on it with an Abort.
+//!
+//! Received transfers are put back together by the [assembler](struct.TransportAssembler.html).
+//! Messages are sent with [broadcast](fn.broadcast.html) or a
+//! [TransportSender](struct.TransportSender.html).
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use can::{CanId, Frame, RawMessage, GLOBAL_ADDRESS};
+
+/// PGN of TP.DT, which carries the data of a message.
+pub const TP_DT: u32 = 60160;
+/// PGN of TP.CM, which manages connections.
+pub const TP_CM: u32 = 60416;
+
+/// Smallest message which can be sent with the transport protocol. Anything shorter fits in a
+/// single frame.
+pub const MIN_SIZE: usize = 9;
+/// Largest message which can be sent with the transport protocol.
+pub const MAX_SIZE: usize = 255 * 7;
+
+/// Maximum time allowed between packets, and between a CTS and the first packet it granted.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(750);
+
+/// Maximum time a sender waits for a CTS or EndOfMsgAck before giving up on a connection.
+pub const DEFAULT_RESPONSE_TIMEOUT: Duration = Duration::from_millis(1250);
+
+/// Group function code of a request to send.
+pub const RTS: u8 = 16;
+/// Group function code of a clear to send.
+pub const CTS: u8 = 17;
+/// Group function code of an end of message acknowledgement.
+pub const END_OF_MSG_ACK: u8 = 19;
+/// Group function code of a broadcast announce message.
+pub const BAM: u8 = 32;
+/// Group function code of a connection abort.
+pub const ABORT: u8 = 255;
+
+/// Abort reason sent when a connection is already in progress with the sender.
+pub const ABORT_BUSY: u8 = 1;
+/// Abort reason sent when the message is larger than can be received.
+pub const ABORT_RESOURCES: u8 = 2;
+/// Abort reason sent when the other side stopped sending within the timeout.
+pub const ABORT_TIMEOUT: u8 = 3;
+
+/// Priority TP.CM frames are sent with.
+const PRIORITY: u8 = 7;
+/// Priority TP.DT frames are sent with.
+const DATA_PRIORITY: u8 = 7;
+
+#[derive(Debug)]
+struct Session {
+    priority: u8,
+    pgn: u32,
+    size: usize,
+    packets: u8,
+    /// Number of packets to grant with each CTS.
+    window: u8,
+    /// Sequence number of the first packet not yet granted with a CTS.
+    granted: u16,
+    broadcast: bool,
+    last: Duration,
+    received: Vec<bool>,
+    data: Vec<u8>,
+}
+
+impl Session {
+    fn is_complete(&self) -> bool {
+        self.received.iter().all(|&r| r)
+    }
+}
+
+/// Reassembles messages sent with the transport protocol.
+///
+/// The assembler tracks one transfer per source and destination address pair, which is all the
+/// protocol allows. Broadcast transfers and transfers between other devices are reassembled by
+/// listening to them. If the assembler is given an address of its own, it also takes part in
+/// transfers sent to that address, and queues the CTS, EndOfMsgAck and Abort frames it needs to
+/// send. These are collected with [take_responses](#method.take_responses) and must be written to
+/// the bus by the caller.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use libnmea::can::{CanId, Frame};
+/// use libnmea::transport::{TransportAssembler, TP_CM, TP_DT};
+///
+/// let mut assembler = TransportAssembler::new(None);
+/// let now = Duration::from_millis(0);
+///
+/// // Broadcast of a 9 byte message of PGN 126464 from address 0x17.
+/// let cm = CanId::new(7, TP_CM, 0x17, 0xff);
+/// let dt = CanId::new(7, TP_DT, 0x17, 0xff);
+/// let bam = Frame::new(cm, &[32, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]).unwrap();
+/// let first = Frame::new(dt, &[1, 1, 2, 3, 4, 5, 6, 7]).unwrap();
+/// let second = Frame::new(dt, &[2, 8, 9, 0xff, 0xff, 0xff, 0xff, 0xff]).unwrap();
+///
+/// assert_eq!(assembler.push(&bam, now), None);
+/// assert_eq!(assembler.push(&first, now), None);
+///
+/// let message = assembler.push(&second, now).unwrap();
+/// assert_eq!(message.id.pgn(), 126464);
+/// assert_eq!(message.data, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+#[derive(Debug)]
+pub struct TransportAssembler {
+    address: Option<u8>,
+    timeout: Duration,
+    sessions: HashMap<(u8, u8), Session>,
+    responses: Vec<Frame>,
+}
+
+impl TransportAssembler {
+    /// Creates an assembler using the [default timeout](constant.DEFAULT_TIMEOUT.html). If
+    /// `address` is given, the assembler answers connections opened to that address.
+    pub fn new(address: Option<u8>) -> TransportAssembler {
+        TransportAssembler::with_timeout(address, DEFAULT_TIMEOUT)
+    }
+
+    /// Creates an assembler which gives up on a transfer when nothing has been received for it
+    /// within `timeout`.
+    pub fn with_timeout(address: Option<u8>, timeout: Duration) -> TransportAssembler {
+        TransportAssembler {
+            address,
+            timeout,
+            sessions: HashMap::new(),
+            responses: Vec::new(),
+        }
+    }
+
+    /// Adds a frame received at time `now`, returning the complete message if this frame
+    /// completed one. Frames other than TP.CM and TP.DT are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use libnmea::can::{CanId, Frame};
+    /// use libnmea::transport::{TransportAssembler, ABORT, ABORT_RESOURCES, TP_CM};
+    ///
+    /// let mut assembler = TransportAssembler::new(Some(0x20));
+    ///
+    /// // RTS from 0x17 for an empty message of no packets.
+    /// let rts = CanId::new(7, TP_CM, 0x17, 0x20);
+    /// let frame = Frame::new(rts, &[16, 0, 0, 0, 0xff, 0x00, 0xee, 0x01]).unwrap();
+    ///
+    /// assert_eq!(assembler.push(&frame, Duration::from_millis(0)), None);
+    /// assert_eq!(assembler.pending(), 0);
+    ///
+    /// let responses = assembler.take_responses();
+    /// assert_eq!(responses.len(), 1);
+    /// assert_eq!(responses[0].data()[..2], [ABORT, ABORT_RESOURCES]);
+    /// ```
+    pub fn push(&mut self, frame: &Frame, now: Duration) -> Option<RawMessage> {
+        self.expire(now);
+
+        match frame.id.pgn() {
+            TP_CM => {
+                self.connection_management(frame, now);
+                None
+            }
+            TP_DT => self.data_transfer(frame, now),
+            _ => None,
+        }
+    }
+
+    /// Removes and returns the frames which need to be sent in response to the frames received
+    /// so far.
+    pub fn take_responses(&mut self) -> Vec<Frame> {
+        self.responses.split_off(0)
+    }
+
+    /// Gives up on transfers which have timed out by time `now`, returning how many were
+    /// dropped. Connections to this assembler's address are aborted.
+    pub fn expire(&mut self, now: Duration) -> usize {
+        let timeout = self.timeout;
+        let expired: Vec<(u8, u8)> = self
+            .sessions
+            .iter()
+            .filter(|&(_, s)| now.saturating_sub(s.last) > timeout)
+            .map(|(&key, _)| key)
+            .collect();
+
+        for &(source, destination) in &expired {
+            if let Some(session) = self.sessions.remove(&(source, destination)) {
+                if !session.broadcast && Some(destination) == self.address {
+                    self.abort(destination, source, session.pgn, ABORT_TIMEOUT);
+                }
+            }
+        }
+
+        expired.len()
+    }
+
+    /// Number of transfers in progress.
+    pub fn pending(&self) -> usize {
+        self.sessions.len()
+    }
+
+    fn connection_management(&mut self, frame: &Frame, now: Duration) {
+        let data = frame.data();
+        if data.len() < 8 {
+            return;
+        }
+
+        let source = frame.id.source();
+        let destination = frame.id.destination();
+        let pgn = u32::from(data[5]) | u32::from(data[6]) << 8 | u32::from(data[7]) << 16;
+        let size = data[1] as usize | (data[2] as usize) << 8;
+        let packets = data[3];
+
+        match data[0] {
+            BAM | RTS => {
+                let broadcast = data[0] == BAM;
+                if broadcast != (destination == GLOBAL_ADDRESS) {
+                    return;
+                }
+
+                let to_us = !broadcast && Some(destination) == self.address;
+                if !(MIN_SIZE..=MAX_SIZE).contains(&size) || (packets as usize) < size.div_ceil(7) {
+                    if to_us {
+                        self.abort(destination, source, pgn, ABORT_RESOURCES);
+                    }
+                    return;
+                }
+
+                let window = if broadcast {
+                    packets
+                } else {
+                    data[4].clamp(1, packets.max(1))
+                };
+                self.sessions.insert(
+                    (source, destination),
+                    Session {
+                        priority: frame.id.priority(),
+                        pgn,
+                        size,
+                        packets,
+                        window,
+                        granted: 1,
+                        broadcast,
+                        last: now,
+                        received: vec![false; packets as usize],
+                        data: vec![0xff; packets as usize * 7],
+                    },
+                );
+
+                if to_us {
+                    self.clear_to_send(source, destination);
+                }
+            }
+            ABORT => {
+                // Either side of a connection may abort it.
+                self.sessions.remove(&(source, destination));
+                self.sessions.remove(&(destination, source));
+            }
+            _ => {
+                // CTS and EndOfMsgAck come from the receiving side of a connection. There is
+                // nothing to track for them beyond the packets which follow.
+                if let Some(session) = self.sessions.get_mut(&(destination, source)) {
+                    session.last = now;
+                }
+            }
+        }
+    }
+
+    fn data_transfer(&mut self, frame: &Frame, now: Duration) -> Option<RawMessage> {
+        let data = frame.data();
+        let source = frame.id.source();
+        let destination = frame.id.destination();
+        let to_us = destination != GLOBAL_ADDRESS && Some(destination) == self.address;
+
+        let (complete, window_done) = {
+            let session = self.sessions.get_mut(&(source, destination))?;
+            let sequence = *data.first()? as usize;
+            if sequence == 0 || sequence > session.packets as usize {
+                return None;
+            }
+
+            let offset = (sequence - 1) * 7;
+            let len = (data.len() - 1).min(7);
+            session.data[offset..offset + len].copy_from_slice(&data[1..1 + len]);
+            session.received[sequence - 1] = true;
+            session.last = now;
+
+            (
+                session.is_complete(),
+                sequence + 1 >= session.granted as usize,
+            )
+        };
+
+        if complete {
+            let session = self.sessions.remove(&(source, destination))?;
+            if to_us {
+                let ack = [
+                    END_OF_MSG_ACK,
+                    session.size as u8,
+                    (session.size >> 8) as u8,
+                    session.packets,
+                    0xff,
+                    session.pgn as u8,
+                    (session.pgn >> 8) as u8,
+                    (session.pgn >> 16) as u8,
+                ];
+                self.respond(destination, source, &ack);
+            }
+
+            let mut data = session.data;
+            data.truncate(session.size);
+            return Some(RawMessage {
+                id: CanId::new(session.priority, session.pgn, source, destination),
+                data,
+            });
+        }
+
+        if to_us && window_done {
+            self.clear_to_send(source, destination);
+        }
+
+        None
+    }
+
+    /// Grants the next window of packets, starting from the first packet not yet received.
+    fn clear_to_send(&mut self, source: u8, destination: u8) {
+        let cts = match self.sessions.get_mut(&(source, destination)) {
+            Some(session) => {
+                let next = session.received.iter().position(|&r| !r).unwrap_or(0) + 1;
+                let count = session
+                    .window
+                    .min((session.packets as usize - next + 1) as u8);
+                session.granted = (next + count as usize) as u16;
+                [
+                    CTS,
+                    count,
+                    next as u8,
+                    0xff,
+                    0xff,
+                    session.pgn as u8,
+                    (session.pgn >> 8) as u8,
+                    (session.pgn >> 16) as u8,
+                ]
+            }
+            None => return,
+        };
+
+        self.respond(destination, source, &cts);
+    }
+
+    fn abort(&mut self, from: u8, to: u8, pgn: u32, reason: u8) {
+        let abort = [
+            ABORT,
+            reason,
+            0xff,
+            0xff,
+            0xff,
+            pgn as u8,
+            (pgn >> 8) as u8,
+            (pgn >> 16) as u8,
+        ];
+        self.respond(from, to, &abort);
+    }
+
+    fn respond(&mut self, from: u8, to: u8, data: &[u8]) {
+        if let Some(frame) = Frame::new(CanId::new(PRIORITY, TP_CM, from, to), data) {
+            self.responses.push(frame);
+        }
+    }
+}
+
+/// Splits a message into a BAM and the TP.DT frames which follow it, for broadcast to every device
+/// on the bus. Returns `None` if the message is smaller than [MIN_SIZE](constant.MIN_SIZE.html) or
+/// larger than [MAX_SIZE](constant.MAX_SIZE.html).
+///
+/// The protocol requires 50 to 200 milliseconds between the frames, which is left to the caller.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::can::{CanId, RawMessage};
+/// use libnmea::transport::broadcast;
+///
+/// let message = RawMessage {
+///     id: CanId::new(6, 126464, 0x17, 0xff),
+///     data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9],
+/// };
+/// let frames = broadcast(&message).unwrap();
+///
+/// assert_eq!(frames[0].data(), &[32, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]);
+/// assert_eq!(frames[1].data(), &[1, 1, 2, 3, 4, 5, 6, 7]);
+/// assert_eq!(frames[2].data(), &[2, 8, 9, 0xff, 0xff, 0xff, 0xff, 0xff]);
+/// ```
+pub fn broadcast(message: &RawMessage) -> Option<Vec<Frame>> {
+    let size = message.data.len();
+    if !(MIN_SIZE..=MAX_SIZE).contains(&size) {
+        return None;
+    }
+
+    let source = message.id.source();
+    let pgn = message.id.pgn();
+    let packets = size.div_ceil(7) as u8;
+
+    let announce = [
+        BAM,
+        size as u8,
+        (size >> 8) as u8,
+        packets,
+        0xff,
+        pgn as u8,
+        (pgn >> 8) as u8,
+        (pgn >> 16) as u8,
+    ];
+
+    let mut frames = vec![Frame::new(
+        CanId::new(PRIORITY, TP_CM, source, GLOBAL_ADDRESS),
+        &announce,
+    )?];
+    frames.extend(data_frames(message, GLOBAL_ADDRESS, 1, packets));
+    Some(frames)
+}
+
+/// State of a [TransportSender](struct.TransportSender.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SenderState {
+    /// Waiting for the receiver to grant packets or acknowledge the message.
+    Waiting,
+    /// The receiver acknowledged the complete message.
+    Complete,
+    /// The connection was aborted for the given reason, either by the receiver or by the sender
+    /// timing out.
+    Aborted(u8),
+}
+
+/// Sends a message to a single device with a connection mode transfer.
+///
+/// The sender opens the connection with an RTS, then answers each CTS from the receiver with the
+/// packets it granted until the receiver acknowledges the whole message.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use libnmea::can::{CanId, Frame, RawMessage};
+/// use libnmea::transport::{SenderState, TransportSender, TP_CM};
+///
+/// let message = RawMessage {
+///     id: CanId::new(6, 126464, 0x17, 0x20),
+///     data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9],
+/// };
+/// let now = Duration::from_millis(0);
+/// let mut sender = TransportSender::new(message, now).unwrap();
+/// assert_eq!(sender.request_to_send().data(), &[16, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]);
+///
+/// let cm = CanId::new(7, TP_CM, 0x20, 0x17);
+/// let cts = Frame::new(cm, &[17, 2, 1, 0xff, 0xff, 0x00, 0xee, 0x01]).unwrap();
+/// assert_eq!(sender.push(&cts, now).len(), 2);
+///
+/// let ack = Frame::new(cm, &[19, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]).unwrap();
+/// sender.push(&ack, now);
+/// assert_eq!(sender.state(), SenderState::Complete);
+/// ```
+#[derive(Debug)]
+pub struct TransportSender {
+    message: RawMessage,
+    packets: u8,
+    state: SenderState,
+    last: Duration,
+    timeout: Duration,
+}
+
+impl TransportSender {
+    /// Creates a sender for a message which was started at time `now`. Returns `None` if the
+    /// message is smaller than [MIN_SIZE](constant.MIN_SIZE.html), larger than
+    /// [MAX_SIZE](constant.MAX_SIZE.html) or is not addressed to a single device.
+    pub fn new(message: RawMessage, now: Duration) -> Option<TransportSender> {
+        let size = message.data.len();
+        if !(MIN_SIZE..=MAX_SIZE).contains(&size) || message.id.destination() == GLOBAL_ADDRESS {
+            return None;
+        }
+
+        Some(TransportSender {
+            packets: size.div_ceil(7) as u8,
+            message,
+            state: SenderState::Waiting,
+            last: now,
+            timeout: DEFAULT_RESPONSE_TIMEOUT,
+        })
+    }
+
+    /// The RTS frame which opens the connection. Send it first, and again to retry the whole
+    /// transfer.
+    pub fn request_to_send(&self) -> Frame {
+        let size = self.message.data.len();
+        let pgn = self.message.id.pgn();
+        let request = [
+            RTS,
+            size as u8,
+            (size >> 8) as u8,
+            self.packets,
+            0xff,
+            pgn as u8,
+            (pgn >> 8) as u8,
+            (pgn >> 16) as u8,
+        ];
+
+        Frame::new(self.cm_id(), &request).expect("RTS is a single frame")
+    }
+
+    /// Handles a frame received at time `now`, returning the frames which need to be sent in
+    /// response. Frames which are not TP.CM frames from the receiver to this sender about this
+    /// message are ignored.
+    pub fn push(&mut self, frame: &Frame, now: Duration) -> Vec<Frame> {
+        let data = frame.data();
+        let pgn = self.message.id.pgn();
+        if self.state != SenderState::Waiting
+            || frame.id.pgn() != TP_CM
+            || frame.id.source() != self.message.id.destination()
+            || frame.id.destination() != self.message.id.source()
+            || data.len() < 8
+            || u32::from(data[5]) | u32::from(data[6]) << 8 | u32::from(data[7]) << 16 != pgn
+        {
+            return Vec::new();
+        }
+
+        self.last = now;
+        match data[0] {
+            CTS => {
+                // A CTS for no packets asks the sender to hold the connection open.
+                let first = data[2].max(1);
+                let last = (first as usize + data[1] as usize - 1).min(self.packets as usize);
+                if data[1] == 0 || first > self.packets {
+                    return Vec::new();
+                }
+                data_frames(
+                    &self.message,
+                    self.message.id.destination(),
+                    first,
+                    last as u8,
+                )
+            }
+            END_OF_MSG_ACK => {
+                self.state = SenderState::Complete;
+                Vec::new()
+            }
+            ABORT => {
+                self.state = SenderState::Aborted(data[1]);
+                Vec::new()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Gives up on the connection if the receiver has not responded by time `now`, returning the
+    /// Abort frame which needs to be sent.
+    pub fn expire(&mut self, now: Duration) -> Option<Frame> {
+        if self.state != SenderState::Waiting || now.saturating_sub(self.last) <= self.timeout {
+            return None;
+        }
+
+        self.state = SenderState::Aborted(ABORT_TIMEOUT);
+        let pgn = self.message.id.pgn();
+        let abort = [
+            ABORT,
+            ABORT_TIMEOUT,
+            0xff,
+            0xff,
+            0xff,
+            pgn as u8,
+            (pgn >> 8) as u8,
+            (pgn >> 16) as u8,
+        ];
+        Frame::new(self.cm_id(), &abort)
+    }
+
+    /// Current state of the connection.
+    pub fn state(&self) -> SenderState {
+        self.state
+    }
+
+    fn cm_id(&self) -> CanId {
+        CanId::new(
+            PRIORITY,
+            TP_CM,
+            self.message.id.source(),
+            self.message.id.destination(),
+        )
+    }
+}
+
+/// TP.DT frames for packets `first` to `last` of a message, padded to 8 bytes with `0xff`.
+fn data_frames(message: &RawMessage, destination: u8, first: u8, last: u8) -> Vec<Frame> {
+    let id = CanId::new(DATA_PRIORITY, TP_DT, message.id.source(), destination);
+
+    (first..=last)
+        .filter_map(|sequence| {
+            let offset = (sequence as usize - 1) * 7;
+            let chunk = message.data.get(offset..)?;
+            let mut packet = [0xff; 8];
+            packet[0] = sequence;
+            let len = chunk.len().min(7);
+            packet[1..1 + len].copy_from_slice(&chunk[..len]);
+            Frame::new(id, &packet)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    /// A message sent with the priority of TP.CM frames, which reassembled messages are given.
+    fn message(size: usize, destination: u8) -> RawMessage {
+        RawMessage {
+            id: CanId::new(PRIORITY, 126464, 0x17, destination),
+            data: (0..size).map(|i| i as u8).collect(),
+        }
+    }
+
+    fn cm(source: u8, destination: u8, data: &[u8]) -> Frame {
+        Frame::new(CanId::new(7, TP_CM, source, destination), data).unwrap()
+    }
+
+    fn dt(source: u8, destination: u8, data: &[u8]) -> Frame {
+        Frame::new(CanId::new(7, TP_DT, source, destination), data).unwrap()
+    }
+
+    /// Passes frames between a sender and an assembler until neither has anything left to send,
+    /// returning the message the assembler received.
+    fn connect(
+        sender: &mut TransportSender,
+        assembler: &mut TransportAssembler,
+        rts: &Frame,
+    ) -> Option<RawMessage> {
+        let mut received = None;
+        assembler.push(rts, ms(0));
+
+        loop {
+            let responses = assembler.take_responses();
+            if responses.is_empty() {
+                return received;
+            }
+            for response in responses {
+                for frame in sender.push(&response, ms(0)) {
+                    if let Some(message) = assembler.push(&frame, ms(0)) {
+                        received = Some(message);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn connection_round_trip() {
+        let message = message(MAX_SIZE, 0x20);
+        let mut sender = TransportSender::new(message.clone(), ms(0)).unwrap();
+        let mut assembler = TransportAssembler::new(Some(0x20));
+
+        let rts = sender.request_to_send();
+        let received = connect(&mut sender, &mut assembler, &rts);
+
+        assert_eq!(received, Some(message));
+        assert_eq!(sender.state(), SenderState::Complete);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn connection_with_small_window() {
+        let message = message(20, 0x20);
+        let mut sender = TransportSender::new(message.clone(), ms(0)).unwrap();
+        let mut assembler = TransportAssembler::new(Some(0x20));
+
+        // RTS asking for one packet per CTS.
+        let mut rts = sender.request_to_send().data().to_vec();
+        rts[4] = 1;
+        let rts = cm(0x17, 0x20, &rts);
+
+        assert_eq!(connect(&mut sender, &mut assembler, &rts), Some(message));
+        assert_eq!(sender.state(), SenderState::Complete);
+    }
+
+    #[test]
+    fn broadcast_round_trip() {
+        let message = message(MAX_SIZE, GLOBAL_ADDRESS);
+        let mut assembler = TransportAssembler::new(None);
+
+        let frames = broadcast(&message).unwrap();
+        assert_eq!(frames.len(), 256);
+
+        let mut received = None;
+        for frame in &frames {
+            received = assembler.push(frame, ms(0));
+        }
+        assert_eq!(received, Some(message));
+        assert!(assembler.take_responses().is_empty());
+    }
+
+    #[test]
+    fn out_of_order_packets() {
+        let message = message(20, GLOBAL_ADDRESS);
+        let frames = broadcast(&message).unwrap();
+        let mut assembler = TransportAssembler::new(None);
+
+        assert_eq!(assembler.push(&frames[0], ms(0)), None);
+        assert_eq!(assembler.push(&frames[3], ms(1)), None);
+        assert_eq!(assembler.push(&frames[1], ms(2)), None);
+        assert_eq!(assembler.push(&frames[1], ms(3)), None);
+        assert_eq!(assembler.push(&frames[2], ms(4)), Some(message));
+    }
+
+    #[test]
+    fn packets_without_announcement() {
+        let mut assembler = TransportAssembler::new(None);
+
+        assert_eq!(
+            assembler.push(&dt(0x17, 0xff, &[1, 1, 2, 3, 4, 5, 6, 7]), ms(0)),
+            None
+        );
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn packet_sequence_out_of_range() {
+        let mut assembler = TransportAssembler::new(None);
+        assembler.push(
+            &cm(0x17, 0xff, &[BAM, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]),
+            ms(0),
+        );
+
+        assert_eq!(
+            assembler.push(&dt(0x17, 0xff, &[0, 1, 2, 3, 4, 5, 6, 7]), ms(1)),
+            None
+        );
+        assert_eq!(
+            assembler.push(&dt(0x17, 0xff, &[3, 1, 2, 3, 4, 5, 6, 7]), ms(2)),
+            None
+        );
+        assert_eq!(assembler.push(&dt(0x17, 0xff, &[]), ms(3)), None);
+        assert_eq!(assembler.pending(), 1);
+    }
+
+    #[test]
+    fn malformed_announcements() {
+        let mut assembler = TransportAssembler::new(Some(0x20));
+
+        // Too short.
+        assembler.push(&cm(0x17, 0xff, &[BAM, 9, 0, 2, 0xff, 0x00, 0xee]), ms(0));
+        // BAM sent to a single device.
+        assembler.push(
+            &cm(0x17, 0x20, &[BAM, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]),
+            ms(0),
+        );
+        // RTS sent to the global address.
+        assembler.push(
+            &cm(0x17, 0xff, &[RTS, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]),
+            ms(0),
+        );
+        // Too few packets for the size.
+        assembler.push(
+            &cm(0x18, 0xff, &[BAM, 15, 0, 2, 0xff, 0x00, 0xee, 0x01]),
+            ms(0),
+        );
+        // Small enough for a single frame.
+        assembler.push(
+            &cm(0x19, 0xff, &[BAM, 8, 0, 2, 0xff, 0x00, 0xee, 0x01]),
+            ms(0),
+        );
+
+        assert_eq!(assembler.pending(), 0);
+        assert!(assembler.take_responses().is_empty());
+    }
+
+    #[test]
+    fn oversized_request_is_aborted() {
+        let mut assembler = TransportAssembler::new(Some(0x20));
+        let size = MAX_SIZE + 1;
+        let rts = [
+            RTS,
+            size as u8,
+            (size >> 8) as u8,
+            255,
+            0xff,
+            0x00,
+            0xee,
+            0x01,
+        ];
+        assembler.push(&cm(0x17, 0x20, &rts), ms(0));
+
+        let responses = assembler.take_responses();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].id.destination(), 0x17);
+        assert_eq!(responses[0].data()[..2], [ABORT, ABORT_RESOURCES]);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn request_to_other_device_is_not_answered() {
+        let mut assembler = TransportAssembler::new(Some(0x20));
+        assembler.push(
+            &cm(0x17, 0x30, &[RTS, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]),
+            ms(0),
+        );
+
+        assert_eq!(assembler.pending(), 1);
+        assert!(assembler.take_responses().is_empty());
+    }
+
+    #[test]
+    fn receiver_timeout_aborts_connection() {
+        let mut assembler = TransportAssembler::with_timeout(Some(0x20), ms(100));
+        assembler.push(
+            &cm(0x17, 0x20, &[RTS, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]),
+            ms(0),
+        );
+        assert_eq!(assembler.take_responses()[0].data()[0], CTS);
+
+        assert_eq!(assembler.expire(ms(100)), 0);
+        assert_eq!(assembler.expire(ms(101)), 1);
+
+        let responses = assembler.take_responses();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].data()[..2], [ABORT, ABORT_TIMEOUT]);
+    }
+
+    #[test]
+    fn late_packet_after_timeout() {
+        let mut assembler = TransportAssembler::with_timeout(None, ms(100));
+        assembler.push(
+            &cm(0x17, 0xff, &[BAM, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]),
+            ms(0),
+        );
+        assembler.push(&dt(0x17, 0xff, &[1, 1, 2, 3, 4, 5, 6, 7]), ms(50));
+
+        assert_eq!(assembler.push(&dt(0x17, 0xff, &[2, 8, 9]), ms(151)), None);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn abort_from_sender() {
+        let mut assembler = TransportAssembler::new(Some(0x20));
+        assembler.push(
+            &cm(0x17, 0x20, &[RTS, 9, 0, 2, 0xff, 0x00, 0xee, 0x01]),
+            ms(0),
+        );
+        assembler.push(
+            &cm(0x17, 0x20, &[ABORT, 1, 0xff, 0xff, 0xff, 0x00, 0xee, 0x01]),
+            ms(1),
+        );
+
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn sender_rejects_unsendable_messages() {
+        assert!(TransportSender::new(message(MIN_SIZE - 1, 0x20), ms(0)).is_none());
+        assert!(TransportSender::new(message(MAX_SIZE + 1, 0x20), ms(0)).is_none());
+        assert!(TransportSender::new(message(MIN_SIZE, GLOBAL_ADDRESS), ms(0)).is_none());
+        assert!(broadcast(&message(MIN_SIZE - 1, GLOBAL_ADDRESS)).is_none());
+        assert!(broadcast(&message(MAX_SIZE + 1, GLOBAL_ADDRESS)).is_none());
+    }
+
+    #[test]
+    fn sender_ignores_unrelated_frames() {
+        let mut sender = TransportSender::new(message(20, 0x20), ms(0)).unwrap();
+
+        // CTS for another PGN.
+        assert!(sender
+            .push(
+                &cm(0x20, 0x17, &[CTS, 3, 1, 0xff, 0xff, 0x00, 0xef, 0x01]),
+                ms(0)
+            )
+            .is_empty());
+        // CTS from another device.
+        assert!(sender
+            .push(
+                &cm(0x21, 0x17, &[CTS, 3, 1, 0xff, 0xff, 0x00, 0xee, 0x01]),
+                ms(0)
+            )
+            .is_empty());
+        // CTS past the last packet.
+        assert!(sender
+            .push(
+                &cm(0x20, 0x17, &[CTS, 1, 4, 0xff, 0xff, 0x00, 0xee, 0x01]),
+                ms(0)
+            )
+            .is_empty());
+        // CTS holding the connection open.
+        assert!(sender
+            .push(
+                &cm(0x20, 0x17, &[CTS, 0, 1, 0xff, 0xff, 0x00, 0xee, 0x01]),
+                ms(0)
+            )
+            .is_empty());
+
+        assert_eq!(sender.state(), SenderState::Waiting);
+    }
+
+    #[test]
+    fn sender_aborted_by_receiver() {
+        let mut sender = TransportSender::new(message(20, 0x20), ms(0)).unwrap();
+        sender.push(
+            &cm(
+                0x20,
+                0x17,
+                &[ABORT, ABORT_BUSY, 0xff, 0xff, 0xff, 0x00, 0xee, 0x01],
+            ),
+            ms(0),
+        );
+
+        assert_eq!(sender.state(), SenderState::Aborted(ABORT_BUSY));
+        assert!(sender
+            .push(
+                &cm(0x20, 0x17, &[CTS, 3, 1, 0xff, 0xff, 0x00, 0xee, 0x01]),
+                ms(0)
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn sender_timeout() {
+        let mut sender = TransportSender::new(message(20, 0x20), ms(0)).unwrap();
+
+        assert_eq!(sender.expire(DEFAULT_RESPONSE_TIMEOUT), None);
+
+        let abort = sender.expire(DEFAULT_RESPONSE_TIMEOUT + ms(1)).unwrap();
+        assert_eq!(abort.id.destination(), 0x20);
+        assert_eq!(abort.data()[..2], [ABORT, ABORT_TIMEOUT]);
+        assert_eq!(sender.state(), SenderState::Aborted(ABORT_TIMEOUT));
+        assert_eq!(sender.expire(DEFAULT_RESPONSE_TIMEOUT * 2), None);
+    }
+}
@@ -0,0 +1,249 @@
+//! Yacht Devices RAW format, used by their USB, Wi-Fi and Ethernet gateways over serial, TCP and
+//! UDP.
+//!
+//! Each line holds one CAN frame:
+//!
+//! ```text
+//! 17:33:21.107 R 19F51323 01 02 03 04 05 06 07 08
+//! ```
+//!
+//! That is the time of day, whether the gateway received the frame from the bus (`R`) or sent it
+//! (`T`), then the identifier and data in hex. To have the gateway send a frame, the host writes
+//! just the identifier and data, as formatted by [transmit_line](fn.transmit_line.html).
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use can::{CanId, Frame};
+use hex;
+
+/// Errors which may occur while parsing a line of Yacht Devices RAW.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum YachtDevicesError {
+    /// The line is not laid out as the format requires.
+    Syntax,
+}
+
+impl fmt::Display for YachtDevicesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            YachtDevicesError::Syntax => write!(f, "malformed line"),
+        }
+    }
+}
+
+impl Error for YachtDevicesError {}
+
+/// Which way a frame went through the gateway.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Received by the gateway from the bus.
+    Received,
+    /// Sent by the gateway to the bus.
+    Transmitted,
+}
+
+/// A line of Yacht Devices RAW.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Time of day the frame went through the gateway, since midnight.
+    pub timestamp: Duration,
+    /// Which way the frame went.
+    pub direction: Direction,
+    /// The frame.
+    pub frame: Frame,
+}
+
+impl Record {
+    /// Parses a line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libnmea::yacht_devices::{Direction, Record};
+    ///
+    /// let line = "17:33:21.107 R 19F51323 01 02 03 04 05 06 07 08";
+    /// let record = Record::parse(line).unwrap();
+    ///
+    /// assert_eq!(record.direction, Direction::Received);
+    /// assert_eq!(record.frame.id.source(), 0x23);
+    /// assert_eq!(record.to_string(), line);
+    ///
+    /// assert!(Record::parse("17:33:21.107 R 1FF 01 02").is_err());
+    /// assert!(Record::parse("17:33:21.107 R FFFFFFFFF 01 02").is_err());
+    /// assert!(Record::parse("17:33:21.1e0 R 19F51323 01 02").is_err());
+    /// assert!(Record::parse("17:33:+21 R 19F51323 01 02").is_err());
+    /// ```
+    pub fn parse(line: &str) -> Result<Record, YachtDevicesError> {
+        let mut parts = line.split_whitespace();
+        let time = parts.next().ok_or(YachtDevicesError::Syntax)?;
+        let direction = match parts.next() {
+            Some("R") => Direction::Received,
+            Some("T") => Direction::Transmitted,
+            _ => return Err(YachtDevicesError::Syntax),
+        };
+        let id = parts.next().ok_or(YachtDevicesError::Syntax)?;
+
+        let timestamp = parse_time(time).ok_or(YachtDevicesError::Syntax)?;
+        // Standard 11-bit identifiers are written with 3 digits, and are not NMEA 2000.
+        if id.len() != 8 || !hex::is_hex(id) {
+            return Err(YachtDevicesError::Syntax);
+        }
+        let id = u32::from_str_radix(id, 16).map_err(|_| YachtDevicesError::Syntax)?;
+        let data = parts
+            .map(|p| {
+                if p.len() == 2 && hex::is_hex(p) {
+                    u8::from_str_radix(p, 16).ok()
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or(YachtDevicesError::Syntax)?;
+        let frame = Frame::new(CanId::from_raw(id), &data).ok_or(YachtDevicesError::Syntax)?;
+
+        Ok(Record {
+            timestamp,
+            direction,
+            frame,
+        })
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let seconds = self.timestamp.as_secs();
+        let direction = match self.direction {
+            Direction::Received => "R",
+            Direction::Transmitted => "T",
+        };
+
+        write!(
+            f,
+            "{:02}:{:02}:{:02}.{:03} {} {}",
+            seconds / 3600 % 24,
+            seconds / 60 % 60,
+            seconds % 60,
+            self.timestamp.subsec_millis(),
+            direction,
+            transmit_line(&self.frame)
+        )
+    }
+}
+
+/// Formats a frame as the line the host writes to have the gateway send it, without the line
+/// ending. The gateway expects lines to end with `\r\n`.
+///
+/// # Examples
+///
+/// ```
+/// use libnmea::can::{CanId, Frame};
+/// use libnmea::yacht_devices::transmit_line;
+///
+/// let frame = Frame::new(CanId::from_raw(0x18ea2301), &[0x00, 0xee, 0x00]).unwrap();
+///
+/// assert_eq!(transmit_line(&frame), "18EA2301 00 EE 00");
+/// ```
+pub fn transmit_line(frame: &Frame) -> String {
+    if frame.data().is_empty() {
+        frame.id.to_string()
+    } else {
+        format!("{} {}", frame.id, hex::encode(frame.data(), " "))
+    }
+}
+
+/// Parses a time of day written as `hh:mm:ss.ddd`.
+fn parse_time(text: &str) -> Option<Duration> {
+    let digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    let mut parts = text.split(':');
+    let (hours, minutes, seconds) = (parts.next()?, parts.next()?, parts.next()?);
+    let (whole, fraction) = match seconds.find('.') {
+        Some(dot) => (&seconds[..dot], &seconds[dot + 1..]),
+        None => (seconds, "0"),
+    };
+    if parts.next().is_some()
+        || !digits(hours)
+        || !digits(minutes)
+        || !digits(whole)
+        || !digits(fraction)
+    {
+        return None;
+    }
+
+    let hours: u64 = hours.parse().ok()?;
+    let minutes: u64 = minutes.parse().ok()?;
+    let seconds: f64 = seconds.parse().ok()?;
+    if hours > 23 || minutes > 59 || !(0.0..61.0).contains(&seconds) {
+        return None;
+    }
+
+    let millis = (seconds * 1000.0).round() as u64;
+    Some(Duration::from_millis(
+        (hours * 60 + minutes) * 60_000 + millis,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transmitted_frame_without_data() {
+        let record = Record::parse("00:00:00.000 T 18EA2301\r\n").unwrap();
+
+        assert_eq!(record.direction, Direction::Transmitted);
+        assert!(record.frame.data().is_empty());
+        assert_eq!(record.to_string(), "00:00:00.000 T 18EA2301");
+    }
+
+    #[test]
+    fn times() {
+        let time = |line: &str| Record::parse(line).unwrap().timestamp;
+
+        assert_eq!(
+            time("23:59:59.999 R 19F51323"),
+            Duration::from_millis(86_399_999)
+        );
+        assert_eq!(time("1:2:3 R 19F51323"), Duration::from_secs(3723));
+        assert_eq!(
+            time("01:02:03.5 R 19F51323"),
+            Duration::from_millis(3_723_500)
+        );
+    }
+
+    #[test]
+    fn rejected_lines() {
+        for line in &[
+            "",
+            "17:33:21.107",
+            "17:33:21.107 R",
+            "17:33:21.107 X 19F51323 01",
+            "17:33:21.107 r 19F51323 01",
+            "17:33:21.107 R 19F5132 01",
+            "17:33:21.107 R 19F5132G 01",
+            "17:33:21.107 R +9F51323 01",
+            "17:33:21.107 R 19F51323 1",
+            "17:33:21.107 R 19F51323 001",
+            "17:33:21.107 R 19F51323 0G",
+            "17:33:21.107 R 19F51323 +1",
+            "17:33:21.107 R 19F51323 01 02 03 04 05 06 07 08 09",
+            "17:33 R 19F51323 01",
+            "17:33:21:00 R 19F51323 01",
+            "24:33:21.107 R 19F51323 01",
+            "17:60:21.107 R 19F51323 01",
+            "17:33:61.000 R 19F51323 01",
+            "17::21.107 R 19F51323 01",
+            "17:33:21. R 19F51323 01",
+            "17:33:.107 R 19F51323 01",
+            "17:33:21.-1 R 19F51323 01",
+        ] {
+            assert_eq!(
+                Record::parse(line),
+                Err(YachtDevicesError::Syntax),
+                "{}",
+                line
+            );
+        }
+    }
+}
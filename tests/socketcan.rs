@@ -0,0 +1,29 @@
+//! Needs a `vcan0` interface, so is ignored unless asked for:
+//!
+//! ```text
+//! ip link add dev vcan0 type vcan
+//! ip link set up vcan0
+//! cargo test -- --ignored
+//! ```
+#![cfg(target_os = "linux")]
+
+extern crate libnmea;
+
+use libnmea::socketcan::CanSocket;
+use libnmea::{CanId, Frame};
+
+#[test]
+#[ignore]
+fn vcan_loopback() {
+    let mut sender = CanSocket::open("vcan0").unwrap();
+    let mut receiver = CanSocket::open("vcan0").unwrap();
+
+    // Rate of Turn from address 36.
+    let id = CanId::new(2, 127251, 36, 0xff);
+    let frame = Frame::new(id, &[0x7d, 0x0b, 0x7d, 0x02, 0x00, 0xff, 0xff, 0xff]).unwrap();
+    sender.write_frame(&frame).unwrap();
+
+    let received = receiver.read_frame().unwrap();
+    assert_eq!(received.id, id);
+    assert_eq!(received.data(), frame.data());
+}